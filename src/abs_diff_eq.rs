@@ -1,6 +1,43 @@
-use core::cell;
+#[cfg(feature = "bigdecimal")]
+use bigdecimal::BigDecimal;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, TimeZone};
+use core::marker::PhantomData;
+use core::num::{
+    NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU16, NonZeroU32,
+    NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+use core::time::Duration;
+use core::{cell, cmp, num};
+#[cfg(feature = "fixed")]
+use fixed::types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8};
+#[cfg(feature = "fixed")]
+use fixed::{
+    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
+    FixedU8,
+};
+#[cfg(feature = "serde_json")]
+use json;
+#[cfg(feature = "num-bigint")]
+use num_bigint::{BigInt, BigUint};
 #[cfg(feature = "num-complex")]
 use num_complex::Complex;
+#[cfg(feature = "num-rational")]
+use num_integer::Integer;
+#[cfg(feature = "num-rational")]
+use num_rational::Ratio;
+#[cfg(any(feature = "num-bigint", feature = "num-rational"))]
+use num_traits::Zero;
+#[cfg(feature = "num-rational")]
+use num_traits::{CheckedMul, CheckedSub};
+#[cfg(feature = "ordered-float")]
+use ordered_float::{NotNan, OrderedFloat};
+#[cfg(feature = "rust_decimal")]
+use rust_decimal::Decimal;
+#[cfg(feature = "serde_json")]
+use serde_json::Value;
+#[cfg(feature = "std")]
+use std::time::{Instant, SystemTime};
 
 /// Equality that is defined using the absolute difference of two numbers.
 pub trait AbsDiffEq<Rhs = Self>: PartialEq<Rhs>
@@ -78,13 +115,287 @@ macro_rules! impl_signed_abs_diff_eq {
     };
 }
 
-impl_signed_abs_diff_eq!(i8, 0);
-impl_signed_abs_diff_eq!(i16, 0);
-impl_signed_abs_diff_eq!(i32, 0);
-impl_signed_abs_diff_eq!(i64, 0);
-impl_signed_abs_diff_eq!(isize, 0);
-impl_signed_abs_diff_eq!(f32, core::f32::EPSILON);
-impl_signed_abs_diff_eq!(f64, core::f64::EPSILON);
+impl_signed_abs_diff_eq!(f32, f32::EPSILON);
+impl_signed_abs_diff_eq!(f64, f64::EPSILON);
+
+// `abs(self - other)` overflows for operands of opposite sign, such as `MIN` and `1`, so compare
+// the unsigned distance between them instead
+macro_rules! impl_signed_int_abs_diff_eq {
+    ($T:ident, $U:ident) => {
+        impl AbsDiffEq for $T {
+            type Epsilon = $T;
+
+            #[inline]
+            fn default_epsilon() -> $T {
+                0
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &$T, epsilon: $T) -> bool {
+                epsilon >= 0 && self.abs_diff(*other) <= epsilon as $U
+            }
+        }
+    };
+}
+
+impl_signed_int_abs_diff_eq!(i8, u8);
+impl_signed_int_abs_diff_eq!(i16, u16);
+impl_signed_int_abs_diff_eq!(i32, u32);
+impl_signed_int_abs_diff_eq!(i64, u64);
+impl_signed_int_abs_diff_eq!(isize, usize);
+
+impl AbsDiffEq for () {
+    type Epsilon = ();
+
+    #[inline]
+    fn default_epsilon() {}
+
+    #[inline]
+    fn abs_diff_eq(&self, _other: &(), _epsilon: ()) -> bool {
+        true
+    }
+}
+
+impl<T: ?Sized> AbsDiffEq for PhantomData<T> {
+    type Epsilon = ();
+
+    #[inline]
+    fn default_epsilon() {}
+
+    #[inline]
+    fn abs_diff_eq(&self, _other: &PhantomData<T>, _epsilon: ()) -> bool {
+        true
+    }
+}
+
+impl AbsDiffEq for Duration {
+    type Epsilon = Duration;
+
+    #[inline]
+    fn default_epsilon() -> Duration {
+        Duration::ZERO
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Duration, epsilon: Duration) -> bool {
+        (if self > other {
+            *self - *other
+        } else {
+            *other - *self
+        }) <= epsilon
+    }
+}
+
+#[cfg(feature = "std")]
+impl AbsDiffEq for Instant {
+    type Epsilon = Duration;
+
+    #[inline]
+    fn default_epsilon() -> Duration {
+        Duration::ZERO
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Instant, epsilon: Duration) -> bool {
+        (if self > other {
+            *self - *other
+        } else {
+            *other - *self
+        }) <= epsilon
+    }
+}
+
+#[cfg(feature = "std")]
+impl AbsDiffEq for SystemTime {
+    type Epsilon = Duration;
+
+    #[inline]
+    fn default_epsilon() -> Duration {
+        Duration::ZERO
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &SystemTime, epsilon: Duration) -> bool {
+        (match self.duration_since(*other) {
+            Ok(diff) => diff,
+            Err(err) => err.duration(),
+        }) <= epsilon
+    }
+}
+
+// Compare a difference computed with a checked subtraction against the tolerance. The difference
+// overflows only when it is far larger than any sensible tolerance, so an overflowing difference
+// is never within it.
+#[cfg(any(
+    feature = "chrono",
+    feature = "time",
+    feature = "num-rational",
+    feature = "rust_decimal"
+))]
+#[inline]
+fn checked_abs_diff_le<T: PartialOrd>(abs_diff: Option<T>, epsilon: T) -> bool {
+    abs_diff.is_some_and(|abs_diff| abs_diff <= epsilon)
+}
+
+// Time points whose difference is a signed duration with an `abs` method
+#[cfg(any(feature = "chrono", feature = "time"))]
+macro_rules! impl_time_point_abs_diff_eq {
+    ($T:ty, $Delta:ty, $zero:expr) => {
+        impl AbsDiffEq for $T {
+            type Epsilon = $Delta;
+
+            #[inline]
+            fn default_epsilon() -> $Delta {
+                $zero
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &$T, epsilon: $Delta) -> bool {
+                (*self - *other).abs() <= epsilon
+            }
+        }
+    };
+}
+
+#[cfg(feature = "chrono")]
+impl_time_point_abs_diff_eq!(NaiveDate, TimeDelta, TimeDelta::zero());
+#[cfg(feature = "chrono")]
+impl_time_point_abs_diff_eq!(NaiveTime, TimeDelta, TimeDelta::zero());
+#[cfg(feature = "chrono")]
+impl_time_point_abs_diff_eq!(NaiveDateTime, TimeDelta, TimeDelta::zero());
+
+#[cfg(feature = "chrono")]
+impl<Tz: TimeZone> AbsDiffEq for DateTime<Tz> {
+    type Epsilon = TimeDelta;
+
+    #[inline]
+    fn default_epsilon() -> TimeDelta {
+        TimeDelta::zero()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &DateTime<Tz>, epsilon: TimeDelta) -> bool {
+        self.naive_utc()
+            .signed_duration_since(other.naive_utc())
+            .abs()
+            <= epsilon
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl AbsDiffEq for TimeDelta {
+    type Epsilon = TimeDelta;
+
+    #[inline]
+    fn default_epsilon() -> TimeDelta {
+        TimeDelta::zero()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &TimeDelta, epsilon: TimeDelta) -> bool {
+        checked_abs_diff_le(self.checked_sub(other).map(|diff| diff.abs()), epsilon)
+    }
+}
+
+#[cfg(feature = "time")]
+impl_time_point_abs_diff_eq!(time::Date, time::Duration, time::Duration::ZERO);
+#[cfg(feature = "time")]
+impl_time_point_abs_diff_eq!(time::Time, time::Duration, time::Duration::ZERO);
+#[cfg(feature = "time")]
+impl_time_point_abs_diff_eq!(
+    time::PrimitiveDateTime,
+    time::Duration,
+    time::Duration::ZERO
+);
+#[cfg(feature = "time")]
+impl_time_point_abs_diff_eq!(time::OffsetDateTime, time::Duration, time::Duration::ZERO);
+
+#[cfg(feature = "time")]
+impl AbsDiffEq for time::Duration {
+    type Epsilon = time::Duration;
+
+    #[inline]
+    fn default_epsilon() -> time::Duration {
+        time::Duration::ZERO
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &time::Duration, epsilon: time::Duration) -> bool {
+        checked_abs_diff_le(self.checked_sub(*other).map(|diff| diff.abs()), epsilon)
+    }
+}
+
+// Fixed-point numbers are compared using the difference of their underlying integers, which
+// can not overflow
+#[cfg(feature = "fixed")]
+macro_rules! impl_fixed_abs_diff_eq {
+    ($T:ident, $LeEq:ident) => {
+        impl<Frac: $LeEq> AbsDiffEq for $T<Frac> {
+            type Epsilon = $T<Frac>;
+
+            #[inline]
+            fn default_epsilon() -> $T<Frac> {
+                $T::DELTA
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &$T<Frac>, epsilon: $T<Frac>) -> bool {
+                // A negative epsilon tolerates nothing
+                epsilon >= $T::<Frac>::ZERO
+                    && self.to_bits().abs_diff(other.to_bits()) <= epsilon.to_bits().abs_diff(0)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "fixed")]
+impl_fixed_abs_diff_eq!(FixedI8, LeEqU8);
+#[cfg(feature = "fixed")]
+impl_fixed_abs_diff_eq!(FixedI16, LeEqU16);
+#[cfg(feature = "fixed")]
+impl_fixed_abs_diff_eq!(FixedI32, LeEqU32);
+#[cfg(feature = "fixed")]
+impl_fixed_abs_diff_eq!(FixedI64, LeEqU64);
+#[cfg(feature = "fixed")]
+impl_fixed_abs_diff_eq!(FixedI128, LeEqU128);
+#[cfg(feature = "fixed")]
+impl_fixed_abs_diff_eq!(FixedU8, LeEqU8);
+#[cfg(feature = "fixed")]
+impl_fixed_abs_diff_eq!(FixedU16, LeEqU16);
+#[cfg(feature = "fixed")]
+impl_fixed_abs_diff_eq!(FixedU32, LeEqU32);
+#[cfg(feature = "fixed")]
+impl_fixed_abs_diff_eq!(FixedU64, LeEqU64);
+#[cfg(feature = "fixed")]
+impl_fixed_abs_diff_eq!(FixedU128, LeEqU128);
+
+#[cfg(feature = "ordered-float")]
+macro_rules! impl_ordered_float_abs_diff_eq {
+    ($Wrapper:ident, $T:ident) => {
+        impl AbsDiffEq for $Wrapper<$T> {
+            type Epsilon = $T;
+
+            #[inline]
+            fn default_epsilon() -> $T {
+                $T::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &$Wrapper<$T>, epsilon: $T) -> bool {
+                $T::abs_diff_eq(&**self, &**other, epsilon)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "ordered-float")]
+impl_ordered_float_abs_diff_eq!(OrderedFloat, f32);
+#[cfg(feature = "ordered-float")]
+impl_ordered_float_abs_diff_eq!(OrderedFloat, f64);
+#[cfg(feature = "ordered-float")]
+impl_ordered_float_abs_diff_eq!(NotNan, f32);
+#[cfg(feature = "ordered-float")]
+impl_ordered_float_abs_diff_eq!(NotNan, f64);
 
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 // Derived implementations
@@ -146,6 +457,57 @@ impl<T: AbsDiffEq + ?Sized> AbsDiffEq for cell::RefCell<T> {
     }
 }
 
+macro_rules! impl_wrapper_abs_diff_eq {
+    ($($Wrapper:ident)::+) => {
+        impl<T: AbsDiffEq> AbsDiffEq for $($Wrapper)::+<T> {
+            type Epsilon = T::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> T::Epsilon {
+                T::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &$($Wrapper)::+<T>, epsilon: T::Epsilon) -> bool {
+                T::abs_diff_eq(&self.0, &other.0, epsilon)
+            }
+        }
+    };
+}
+
+impl_wrapper_abs_diff_eq!(num::Wrapping);
+impl_wrapper_abs_diff_eq!(num::Saturating);
+impl_wrapper_abs_diff_eq!(cmp::Reverse);
+
+macro_rules! impl_non_zero_abs_diff_eq {
+    ($T:ident, $U:ident) => {
+        impl AbsDiffEq for $T {
+            type Epsilon = $U;
+
+            #[inline]
+            fn default_epsilon() -> $U {
+                $U::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &$T, epsilon: $U) -> bool {
+                $U::abs_diff_eq(&self.get(), &other.get(), epsilon)
+            }
+        }
+    };
+}
+
+impl_non_zero_abs_diff_eq!(NonZeroU8, u8);
+impl_non_zero_abs_diff_eq!(NonZeroU16, u16);
+impl_non_zero_abs_diff_eq!(NonZeroU32, u32);
+impl_non_zero_abs_diff_eq!(NonZeroU64, u64);
+impl_non_zero_abs_diff_eq!(NonZeroUsize, usize);
+impl_non_zero_abs_diff_eq!(NonZeroI8, i8);
+impl_non_zero_abs_diff_eq!(NonZeroI16, i16);
+impl_non_zero_abs_diff_eq!(NonZeroI32, i32);
+impl_non_zero_abs_diff_eq!(NonZeroI64, i64);
+impl_non_zero_abs_diff_eq!(NonZeroIsize, isize);
+
 impl<A, B> AbsDiffEq<[B]> for [A]
 where
     A: AbsDiffEq<B>,
@@ -183,3 +545,113 @@ where
             && T::abs_diff_eq(&self.im, &other.im, epsilon)
     }
 }
+
+#[cfg(feature = "serde_json")]
+impl AbsDiffEq for Value {
+    type Epsilon = f64;
+
+    #[inline]
+    fn default_epsilon() -> f64 {
+        f64::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Value, epsilon: f64) -> bool {
+        json::abs_diff_eq(self, other, epsilon).is_ok()
+    }
+}
+
+#[cfg(feature = "num-rational")]
+impl<T: Clone + Integer + CheckedMul + CheckedSub> AbsDiffEq for Ratio<T> {
+    type Epsilon = Ratio<T>;
+
+    #[inline]
+    fn default_epsilon() -> Ratio<T> {
+        Ratio::zero()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Ratio<T>, epsilon: Ratio<T>) -> bool {
+        let abs_diff = if self > other {
+            self.checked_sub(other)
+        } else {
+            other.checked_sub(self)
+        };
+        checked_abs_diff_le(abs_diff, epsilon)
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl AbsDiffEq for BigInt {
+    type Epsilon = BigInt;
+
+    #[inline]
+    fn default_epsilon() -> BigInt {
+        BigInt::zero()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &BigInt, epsilon: BigInt) -> bool {
+        (if self > other {
+            self - other
+        } else {
+            other - self
+        }) <= epsilon
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl AbsDiffEq for BigUint {
+    type Epsilon = BigUint;
+
+    #[inline]
+    fn default_epsilon() -> BigUint {
+        BigUint::zero()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &BigUint, epsilon: BigUint) -> bool {
+        (if self > other {
+            self - other
+        } else {
+            other - self
+        }) <= epsilon
+    }
+}
+
+// The default epsilon is one unit in the finest decimal place that a `Decimal` can represent,
+// `1e-28`.
+#[cfg(feature = "rust_decimal")]
+impl AbsDiffEq for Decimal {
+    type Epsilon = Decimal;
+
+    #[inline]
+    fn default_epsilon() -> Decimal {
+        Decimal::new(1, Decimal::MAX_SCALE)
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Decimal, epsilon: Decimal) -> bool {
+        checked_abs_diff_le(
+            Decimal::checked_sub(*self, *other).map(|diff| diff.abs()),
+            epsilon,
+        )
+    }
+}
+
+// The default epsilon is `1e-100`, matching the 100 significant digits that `BigDecimal` keeps
+// by default when the result of an operation can not be represented exactly.
+#[cfg(feature = "bigdecimal")]
+impl AbsDiffEq for BigDecimal {
+    type Epsilon = BigDecimal;
+
+    #[inline]
+    fn default_epsilon() -> BigDecimal {
+        BigDecimal::new(1.into(), 100)
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &BigDecimal, epsilon: BigDecimal) -> bool {
+        (self - other).abs() <= epsilon
+    }
+}
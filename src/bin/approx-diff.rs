@@ -0,0 +1,232 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compare two files of comma or whitespace separated numbers, field by field.
+//!
+//! Fields that parse as numbers on both sides are compared approximately, and all other fields
+//! exactly. Each differing field is printed along with its measured error, and the exit status
+//! is `0` if the files are equal, `1` if they differ, and `2` on errors.
+
+extern crate approx;
+
+use std::env;
+use std::fmt;
+use std::fs;
+use std::process;
+
+use approx::Tolerance;
+
+const USAGE: &str = "\
+usage: approx-diff [OPTIONS] <LEFT> <RIGHT>
+
+Compare two files of comma or whitespace separated numbers, field by field.
+
+options:
+    --abs <EPSILON>        the absolute tolerance
+    --rel <MAX_RELATIVE>   the relative tolerance
+    --ulps <MAX_ULPS>      the tolerance in units in the last place
+    --tolerance <SPEC>     a combined tolerance, such as `1e-12abs+1e-9rel`
+    -h, --help             print this message
+
+Numbers are equal if they are within any of the given tolerances. Without a
+tolerance, numbers are compared with an absolute tolerance of f64::EPSILON.
+
+The options combine, so `--abs 1e-12 --tolerance 4ulps` tolerates both. When
+a kind of tolerance is given more than once, the last one is used.";
+
+struct Args {
+    tolerance: Tolerance,
+    left: String,
+    right: String,
+}
+
+fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Option<Args>, String> {
+    let mut tolerance = Tolerance::default();
+    let mut paths = Vec::new();
+
+    while let Some(arg) = args.next() {
+        if arg == "-h" || arg == "--help" {
+            return Ok(None);
+        }
+        if arg == "--" {
+            paths.extend(args.by_ref());
+            break;
+        }
+        if !arg.starts_with("--") {
+            paths.push(arg);
+            continue;
+        }
+
+        // Options may be given as `--abs 1e-9` or `--abs=1e-9`
+        let (name, value) = match arg.find('=') {
+            Some(index) => (arg[..index].to_string(), arg[index + 1..].to_string()),
+            None => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| format!("missing value for `{}`", arg))?;
+                (arg, value)
+            }
+        };
+        let invalid = || format!("invalid value for `{}`: `{}`", name, value);
+
+        match &name[..] {
+            "--abs" => tolerance = tolerance.epsilon(value.parse().map_err(|_| invalid())?),
+            "--rel" => tolerance = tolerance.max_relative(value.parse().map_err(|_| invalid())?),
+            "--ulps" => tolerance = tolerance.max_ulps(value.parse().map_err(|_| invalid())?),
+            "--tolerance" => {
+                let spec: Tolerance = value
+                    .parse()
+                    .map_err(|error| format!("invalid tolerance `{}`: {}", value, error))?;
+                tolerance = Tolerance {
+                    epsilon: spec.epsilon.or(tolerance.epsilon),
+                    max_relative: spec.max_relative.or(tolerance.max_relative),
+                    max_ulps: spec.max_ulps.or(tolerance.max_ulps),
+                };
+            }
+            _ => return Err(format!("unknown option `{}`", name)),
+        }
+    }
+
+    let mut paths = paths.into_iter();
+    match (paths.next(), paths.next(), paths.next()) {
+        (Some(left), Some(right), None) => Ok(Some(Args {
+            tolerance,
+            left,
+            right,
+        })),
+        _ => Err("expected two files to compare".to_string()),
+    }
+}
+
+/// Split a line into fields, at commas if there are any, and otherwise at whitespace.
+fn fields(line: &str) -> Vec<&str> {
+    if line.contains(',') {
+        line.split(',').map(str::trim).collect()
+    } else {
+        line.split_whitespace().collect()
+    }
+}
+
+/// The measured error between two numbers.
+struct Error {
+    abs: f64,
+    rel: f64,
+    /// The distance in units in the last place, if the numbers have the same sign.
+    ulps: Option<u64>,
+}
+
+impl Error {
+    fn new(left: f64, right: f64) -> Error {
+        let abs = (left - right).abs();
+        let largest = f64::max(left.abs(), right.abs());
+        let ulps = if left.is_sign_negative() == right.is_sign_negative() {
+            Some(u64::abs_diff(left.to_bits(), right.to_bits()))
+        } else {
+            None
+        };
+        Error {
+            abs,
+            rel: if largest == 0.0 { 0.0 } else { abs / largest },
+            ulps,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "abs {:e}, rel {:e}", self.abs, self.rel)?;
+        match self.ulps {
+            Some(ulps) => write!(f, ", ulps {}", ulps),
+            None => write!(f, ", ulps -"),
+        }
+    }
+}
+
+/// Compare two files, printing every differing field, and returning the number of differences.
+fn compare(left: &str, right: &str, tolerance: &Tolerance) -> usize {
+    let mut differences = 0;
+    let mut left_lines = left.lines();
+    let mut right_lines = right.lines();
+    let mut line = 0;
+
+    loop {
+        line += 1;
+        let (left_fields, right_fields) = match (left_lines.next(), right_lines.next()) {
+            (None, None) => return differences,
+            (left, right) => (
+                left.map(fields).unwrap_or_default(),
+                right.map(fields).unwrap_or_default(),
+            ),
+        };
+
+        for field in 0..usize::max(left_fields.len(), right_fields.len()) {
+            let location = format!("{}:{}", line, field + 1);
+            match (left_fields.get(field), right_fields.get(field)) {
+                // Identical fields are equal, even if they are not finite numbers
+                (Some(left), Some(right)) if left == right => {}
+                (Some(left), Some(right)) => match (left.parse::<f64>(), right.parse::<f64>()) {
+                    (Ok(x), Ok(y)) => {
+                        if tolerance.ne(&x, &y) {
+                            let error = Error::new(x, y);
+                            println!("{}: {} != {} ({})", location, left, right, error);
+                            differences += 1;
+                        }
+                    }
+                    _ => {
+                        println!("{}: {} != {}", location, left, right);
+                        differences += 1;
+                    }
+                },
+                (Some(left), None) => {
+                    println!("{}: {} is missing on the right", location, left);
+                    differences += 1;
+                }
+                (None, Some(right)) => {
+                    println!("{}: {} is missing on the left", location, right);
+                    differences += 1;
+                }
+                (None, None) => unreachable!(),
+            }
+        }
+    }
+}
+
+fn main() {
+    let args = match parse_args(env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return;
+        }
+        Err(error) => {
+            eprintln!("approx-diff: {}\n\n{}", error, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let read = |path: &str| {
+        fs::read_to_string(path).unwrap_or_else(|error| {
+            eprintln!("approx-diff: failed to read `{}`: {}", path, error);
+            process::exit(2);
+        })
+    };
+    let left = read(&args.left);
+    let right = read(&args.right);
+
+    let differences = compare(&left, &right, &args.tolerance);
+    if differences > 0 {
+        eprintln!("approx-diff: {} fields differ", differences);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,430 @@
+use core::fmt;
+use num_traits::Signed;
+
+use {AbsDiff, AbsDiffEq, Periodic, PeriodicEq, Relative, RelativeEq, Ulps, UlpsEq};
+
+/// A strategy for testing two values for approximate equality.
+///
+/// This is implemented by the [`AbsDiff`], [`Relative`] and [`Ulps`] parameter types, and by any
+/// closure of type `Fn(&A, &B) -> bool`, allowing code to be written generically over the kind
+/// of comparison that the caller chooses. Comparators can be used with the [`approx_eq!`] and
+/// [`assert_approx_eq!`] macros via the `using` option.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate approx;
+///
+/// use approx::{Comparator, Relative, Ulps};
+///
+/// fn all_close<C: Comparator<f64>>(cmp: &C, xs: &[f64], ys: &[f64]) -> bool {
+///     xs.len() == ys.len() && xs.iter().zip(ys).all(|(x, y)| cmp.eq(x, y))
+/// }
+///
+/// # fn main() {
+/// assert!(all_close(&Relative::default().max_relative(1e-9), &[1.0, 2.0], &[1.0, 2.0]));
+/// assert!(all_close(&Ulps::default().max_ulps(4), &[0.1 + 0.2], &[0.3]));
+///
+/// let within_half = |a: &f64, b: &f64| (a - b).abs() <= 0.5;
+/// assert_approx_eq!(1.0, 1.25, using = within_half);
+/// # }
+/// ```
+pub trait Comparator<A, B = A>
+where
+    A: ?Sized,
+    B: ?Sized,
+{
+    /// Peform the equality comparison
+    fn eq(&self, lhs: &A, rhs: &B) -> bool;
+
+    /// Peform the inequality comparison
+    fn ne(&self, lhs: &A, rhs: &B) -> bool {
+        !self.eq(lhs, rhs)
+    }
+}
+
+impl<A, B, F> Comparator<A, B> for F
+where
+    A: ?Sized,
+    B: ?Sized,
+    F: Fn(&A, &B) -> bool,
+{
+    #[inline]
+    fn eq(&self, lhs: &A, rhs: &B) -> bool {
+        self(lhs, rhs)
+    }
+}
+
+impl<A, B> Comparator<A, B> for AbsDiff<A, B>
+where
+    A: AbsDiffEq<B> + ?Sized,
+    A::Epsilon: Clone,
+    B: ?Sized,
+{
+    #[inline]
+    fn eq(&self, lhs: &A, rhs: &B) -> bool {
+        A::abs_diff_eq(lhs, rhs, self.epsilon.clone())
+    }
+
+    #[inline]
+    fn ne(&self, lhs: &A, rhs: &B) -> bool {
+        A::abs_diff_ne(lhs, rhs, self.epsilon.clone())
+    }
+}
+
+impl<A, B> Comparator<A, B> for Relative<A, B>
+where
+    A: RelativeEq<B> + ?Sized,
+    A::Epsilon: Clone,
+    B: ?Sized,
+{
+    #[inline]
+    fn eq(&self, lhs: &A, rhs: &B) -> bool {
+        A::relative_eq(lhs, rhs, self.epsilon.clone(), self.max_relative.clone())
+    }
+
+    #[inline]
+    fn ne(&self, lhs: &A, rhs: &B) -> bool {
+        A::relative_ne(lhs, rhs, self.epsilon.clone(), self.max_relative.clone())
+    }
+}
+
+impl<A, B> Comparator<A, B> for Ulps<A, B>
+where
+    A: UlpsEq<B> + ?Sized,
+    A::Epsilon: Clone,
+    B: ?Sized,
+{
+    #[inline]
+    fn eq(&self, lhs: &A, rhs: &B) -> bool {
+        A::ulps_eq(lhs, rhs, self.epsilon.clone(), self.max_ulps)
+    }
+
+    #[inline]
+    fn ne(&self, lhs: &A, rhs: &B) -> bool {
+        A::ulps_ne(lhs, rhs, self.epsilon.clone(), self.max_ulps)
+    }
+}
+
+impl<A, B> Comparator<A, B> for Periodic<A, B>
+where
+    A: PeriodicEq<B> + ?Sized,
+    A::Epsilon: Clone,
+    B: ?Sized,
+{
+    #[inline]
+    fn eq(&self, lhs: &A, rhs: &B) -> bool {
+        A::periodic_eq(lhs, rhs, self.epsilon.clone(), self.period.clone())
+    }
+
+    #[inline]
+    fn ne(&self, lhs: &A, rhs: &B) -> bool {
+        A::periodic_ne(lhs, rhs, self.epsilon.clone(), self.period.clone())
+    }
+}
+
+/// A comparator that succeeds if either of two comparators succeed.
+///
+/// This is usually constructed with the [`either`] function.
+#[derive(Debug, Clone, Copy)]
+pub struct Either<C, D> {
+    /// The first comparator to try.
+    pub first: C,
+    /// The comparator to try if the first one fails.
+    pub second: D,
+}
+
+/// Combine two comparators, succeeding if either of them succeed.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate approx;
+///
+/// use approx::{AbsDiff, Ulps};
+///
+/// # fn main() {
+/// let cmp = approx::either(Ulps::default().max_ulps(4), AbsDiff::default().epsilon(1e-12));
+/// assert_approx_eq!(1e-13, 0.0, using = cmp);
+/// assert_approx_eq!(0.1 + 0.2, 0.3, using = cmp);
+/// # }
+/// ```
+#[inline]
+pub fn either<C, D>(first: C, second: D) -> Either<C, D> {
+    Either { first, second }
+}
+
+impl<A, B, C, D> Comparator<A, B> for Either<C, D>
+where
+    A: ?Sized,
+    B: ?Sized,
+    C: Comparator<A, B>,
+    D: Comparator<A, B>,
+{
+    #[inline]
+    fn eq(&self, lhs: &A, rhs: &B) -> bool {
+        self.first.eq(lhs, rhs) || self.second.eq(lhs, rhs)
+    }
+}
+
+/// A comparator that succeeds only if both of two comparators succeed.
+///
+/// This is usually constructed with the [`both`] function.
+#[derive(Debug, Clone, Copy)]
+pub struct Both<C, D> {
+    /// The first comparator to check.
+    pub first: C,
+    /// The second comparator to check.
+    pub second: D,
+}
+
+/// Combine two comparators, succeeding only if both of them succeed.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate approx;
+///
+/// use approx::{AbsDiff, Relative};
+///
+/// # fn main() {
+/// let cmp = approx::both(AbsDiff::default().epsilon(1.0), Relative::default().max_relative(0.1));
+/// assert_approx_eq!(100.0, 100.5, using = cmp);
+/// assert_approx_ne!(1.0, 1.5, using = cmp);
+/// # }
+/// ```
+#[inline]
+pub fn both<C, D>(first: C, second: D) -> Both<C, D> {
+    Both { first, second }
+}
+
+impl<A, B, C, D> Comparator<A, B> for Both<C, D>
+where
+    A: ?Sized,
+    B: ?Sized,
+    C: Comparator<A, B>,
+    D: Comparator<A, B>,
+{
+    #[inline]
+    fn eq(&self, lhs: &A, rhs: &B) -> bool {
+        self.first.eq(lhs, rhs) && self.second.eq(lhs, rhs)
+    }
+}
+
+/// A comparator that succeeds if any of a collection of comparators succeed.
+///
+/// This is usually constructed with the [`any_of`] function, and is implemented for arrays of
+/// comparators of the same type, and for tuples of up to eight comparators of differing types.
+#[derive(Debug, Clone, Copy)]
+pub struct AnyOf<T>(pub T);
+
+/// Combine a collection of comparators, succeeding if any of them succeed.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate approx;
+///
+/// use approx::{AbsDiff, Relative, Ulps};
+///
+/// # fn main() {
+/// let cmp = approx::any_of((
+///     Ulps::default().max_ulps(4),
+///     AbsDiff::default().epsilon(1e-12),
+///     Relative::default().max_relative(1e-9),
+/// ));
+/// assert_approx_eq!(1.0, 1.0 + 1e-10, using = cmp);
+///
+/// let cmp = approx::any_of([AbsDiff::default().epsilon(1e-12), AbsDiff::default().epsilon(0.5)]);
+/// assert_approx_eq!(1.0, 1.25, using = cmp);
+/// # }
+/// ```
+#[inline]
+pub fn any_of<T>(comparators: T) -> AnyOf<T> {
+    AnyOf(comparators)
+}
+
+/// A comparator that succeeds only if all of a collection of comparators succeed.
+///
+/// This is usually constructed with the [`all_of`] function, and is implemented for arrays of
+/// comparators of the same type, and for tuples of up to eight comparators of differing types.
+#[derive(Debug, Clone, Copy)]
+pub struct AllOf<T>(pub T);
+
+/// Combine a collection of comparators, succeeding only if all of them succeed.
+#[inline]
+pub fn all_of<T>(comparators: T) -> AllOf<T> {
+    AllOf(comparators)
+}
+
+impl<A, B, C, const N: usize> Comparator<A, B> for AnyOf<[C; N]>
+where
+    A: ?Sized,
+    B: ?Sized,
+    C: Comparator<A, B>,
+{
+    #[inline]
+    fn eq(&self, lhs: &A, rhs: &B) -> bool {
+        self.0.iter().any(|cmp| cmp.eq(lhs, rhs))
+    }
+}
+
+impl<A, B, C, const N: usize> Comparator<A, B> for AllOf<[C; N]>
+where
+    A: ?Sized,
+    B: ?Sized,
+    C: Comparator<A, B>,
+{
+    #[inline]
+    fn eq(&self, lhs: &A, rhs: &B) -> bool {
+        self.0.iter().all(|cmp| cmp.eq(lhs, rhs))
+    }
+}
+
+macro_rules! impl_tuple_comparators {
+    ($($C:ident . $i:tt),+) => {
+        impl<A, B, $($C),+> Comparator<A, B> for AnyOf<($($C,)+)>
+        where
+            A: ?Sized,
+            B: ?Sized,
+            $($C: Comparator<A, B>,)+
+        {
+            #[inline]
+            fn eq(&self, lhs: &A, rhs: &B) -> bool {
+                $(self.0.$i.eq(lhs, rhs))||+
+            }
+        }
+
+        impl<A, B, $($C),+> Comparator<A, B> for AllOf<($($C,)+)>
+        where
+            A: ?Sized,
+            B: ?Sized,
+            $($C: Comparator<A, B>,)+
+        {
+            #[inline]
+            fn eq(&self, lhs: &A, rhs: &B) -> bool {
+                $(self.0.$i.eq(lhs, rhs))&&+
+            }
+        }
+    };
+}
+
+impl_tuple_comparators!(C0.0);
+impl_tuple_comparators!(C0.0, C1.1);
+impl_tuple_comparators!(C0.0, C1.1, C2.2);
+impl_tuple_comparators!(C0.0, C1.1, C2.2, C3.3);
+impl_tuple_comparators!(C0.0, C1.1, C2.2, C3.3, C4.4);
+impl_tuple_comparators!(C0.0, C1.1, C2.2, C3.3, C4.4, C5.5);
+impl_tuple_comparators!(C0.0, C1.1, C2.2, C3.3, C4.4, C5.5, C6.6);
+impl_tuple_comparators!(C0.0, C1.1, C2.2, C3.3, C4.4, C5.5, C6.6, C7.7);
+
+/// A comparator that chooses between two strategies based on the magnitude of the expected
+/// (right-hand) value.
+///
+/// This is usually constructed with the [`by_magnitude`] function.
+#[derive(Debug, Clone, Copy)]
+pub struct ByMagnitude<T, C, D> {
+    /// The magnitude at which to switch from the `small` to the `large` comparator.
+    pub threshold: T,
+    /// The comparator to use when the expected value is smaller in magnitude than the threshold.
+    pub small: C,
+    /// The comparator to use otherwise.
+    pub large: D,
+}
+
+/// Use the `small` comparator when the magnitude of the expected value is below `threshold`,
+/// and the `large` comparator otherwise.
+///
+/// This is useful for switching from an absolute to a relative tolerance near zero, where
+/// relative comparisons break down.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate approx;
+///
+/// use approx::{AbsDiff, Relative};
+///
+/// # fn main() {
+/// let cmp = approx::by_magnitude(
+///     1.0,
+///     AbsDiff::default().epsilon(1e-9),
+///     Relative::default().max_relative(1e-6),
+/// );
+/// assert_approx_eq!(1e-10, 0.0, using = cmp);
+/// assert_approx_eq!(1000.0001, 1000.0, using = cmp);
+/// # }
+/// ```
+#[inline]
+pub fn by_magnitude<T, C, D>(threshold: T, small: C, large: D) -> ByMagnitude<T, C, D> {
+    ByMagnitude {
+        threshold,
+        small,
+        large,
+    }
+}
+
+impl<A, B, C, D> Comparator<A, B> for ByMagnitude<B, C, D>
+where
+    A: ?Sized,
+    B: Signed + PartialOrd,
+    C: Comparator<A, B>,
+    D: Comparator<A, B>,
+{
+    #[inline]
+    fn eq(&self, lhs: &A, rhs: &B) -> bool {
+        if rhs.abs() < self.threshold {
+            self.small.eq(lhs, rhs)
+        } else {
+            self.large.eq(lhs, rhs)
+        }
+    }
+}
+
+// The assertion macros print the comparator when it implements `Debug`, and leave it out for
+// closures and other comparators that don't. As the macros can't require `Debug`, this is
+// decided by method resolution, which prefers `__UsingDebug` as it needs no extra reference.
+
+#[doc(hidden)]
+pub struct __Using<'a, C: 'a + ?Sized>(pub &'a C);
+
+#[doc(hidden)]
+pub struct __UsingLine<'a>(Option<&'a dyn fmt::Debug>);
+
+impl<'a> fmt::Display for __UsingLine<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Some(cmp) => writeln!(f, "    using = {:?}", cmp),
+            None => Ok(()),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub trait __UsingDebug {
+    fn __using_line<'c>(&'c self) -> __UsingLine<'c>;
+}
+
+impl<'a, C: fmt::Debug + ?Sized> __UsingDebug for __Using<'a, C> {
+    #[inline]
+    fn __using_line<'c>(&'c self) -> __UsingLine<'c> {
+        __UsingLine(Some(&self.0))
+    }
+}
+
+#[doc(hidden)]
+pub trait __UsingFallback {
+    fn __using_line<'c>(&'c self) -> __UsingLine<'c>;
+}
+
+impl<'a, 'b, C: ?Sized> __UsingFallback for &'b __Using<'a, C> {
+    #[inline]
+    fn __using_line<'c>(&'c self) -> __UsingLine<'c> {
+        __UsingLine(None)
+    }
+}
@@ -0,0 +1,149 @@
+//! Approximate equality of iterators, without collecting them into slices.
+//!
+//! The functions in this module consume two iterators in lockstep, stopping at the first pair of
+//! elements that are not approximately equal, or at the point where one of the iterators runs
+//! out before the other.
+//!
+//! # Example
+//!
+//! ```rust
+//! #[macro_use]
+//! extern crate approx;
+//!
+//! # fn main() {
+//! let xs = (0..1000).map(|i| i as f64 * 0.1);
+//! let ys = (0..1000).map(|i| i as f64 / 10.0);
+//!
+//! assert!(approx::iter::relative_eq(xs.clone(), ys.clone(), 1e-12, 1e-12).is_ok());
+//! assert_iter_ulps_eq!(xs, ys, max_ulps = 4);
+//!
+//! let mismatch = approx::iter::abs_diff_eq(vec![1.0, 2.0, 3.0], vec![1.0, 2.5], 0.1)
+//!     .unwrap_err();
+//! assert_eq!(mismatch.index, 1);
+//! # }
+//! ```
+
+use core::fmt;
+
+use {AbsDiff, AbsDiffEq, Comparator, Relative, RelativeEq, Ulps, UlpsEq};
+
+/// The first point at which two iterators were found to differ.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mismatch<A, B> {
+    /// The index of the first differing element.
+    pub index: usize,
+    /// The left-hand element, or `None` if the left-hand iterator ended first.
+    pub left: Option<A>,
+    /// The right-hand element, or `None` if the right-hand iterator ended first.
+    pub right: Option<B>,
+}
+
+impl<A: fmt::Debug, B: fmt::Debug> fmt::Display for Mismatch<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (&self.left, &self.right) {
+            (Some(left), Some(right)) => write!(
+                f,
+                "elements differ at index {}:\n\n    left  = {:?}\n    right = {:?}",
+                self.index, left, right,
+            ),
+            (None, Some(right)) => write!(
+                f,
+                "left ended at index {}, but right continued with {:?}",
+                self.index, right,
+            ),
+            (Some(left), None) => write!(
+                f,
+                "right ended at index {}, but left continued with {:?}",
+                self.index, left,
+            ),
+            (None, None) => write!(f, "iterators differ at index {}", self.index),
+        }
+    }
+}
+
+/// Compare two iterators element-wise using the supplied [`Comparator`].
+#[inline]
+pub fn compare<I, J, C>(lhs: I, rhs: J, cmp: C) -> Result<(), Mismatch<I::Item, J::Item>>
+where
+    I: IntoIterator,
+    J: IntoIterator,
+    C: Comparator<I::Item, J::Item>,
+{
+    let mut lhs = lhs.into_iter();
+    let mut rhs = rhs.into_iter();
+    let mut index = 0;
+
+    loop {
+        match (lhs.next(), rhs.next()) {
+            (None, None) => return Ok(()),
+            (Some(left), Some(right)) => {
+                if cmp.ne(&left, &right) {
+                    return Err(Mismatch {
+                        index,
+                        left: Some(left),
+                        right: Some(right),
+                    });
+                }
+            }
+            (left, right) => return Err(Mismatch { index, left, right }),
+        }
+        index += 1;
+    }
+}
+
+/// Compare two iterators element-wise using [`AbsDiffEq::abs_diff_eq`].
+#[inline]
+pub fn abs_diff_eq<I, J>(
+    lhs: I,
+    rhs: J,
+    epsilon: <I::Item as AbsDiffEq<J::Item>>::Epsilon,
+) -> Result<(), Mismatch<I::Item, J::Item>>
+where
+    I: IntoIterator,
+    J: IntoIterator,
+    I::Item: AbsDiffEq<J::Item>,
+    <I::Item as AbsDiffEq<J::Item>>::Epsilon: Clone,
+{
+    compare(lhs, rhs, AbsDiff::default().epsilon(epsilon))
+}
+
+/// Compare two iterators element-wise using [`RelativeEq::relative_eq`].
+#[inline]
+pub fn relative_eq<I, J>(
+    lhs: I,
+    rhs: J,
+    epsilon: <I::Item as AbsDiffEq<J::Item>>::Epsilon,
+    max_relative: <I::Item as AbsDiffEq<J::Item>>::Epsilon,
+) -> Result<(), Mismatch<I::Item, J::Item>>
+where
+    I: IntoIterator,
+    J: IntoIterator,
+    I::Item: RelativeEq<J::Item>,
+    <I::Item as AbsDiffEq<J::Item>>::Epsilon: Clone,
+{
+    let cmp = Relative::default()
+        .epsilon(epsilon)
+        .max_relative(max_relative);
+    compare(lhs, rhs, cmp)
+}
+
+/// Compare two iterators element-wise using [`UlpsEq::ulps_eq`].
+#[inline]
+pub fn ulps_eq<I, J>(
+    lhs: I,
+    rhs: J,
+    epsilon: <I::Item as AbsDiffEq<J::Item>>::Epsilon,
+    max_ulps: u32,
+) -> Result<(), Mismatch<I::Item, J::Item>>
+where
+    I: IntoIterator,
+    J: IntoIterator,
+    I::Item: UlpsEq<J::Item>,
+    <I::Item as AbsDiffEq<J::Item>>::Epsilon: Clone,
+{
+    compare(
+        lhs,
+        rhs,
+        Ulps::default().epsilon(epsilon).max_ulps(max_ulps),
+    )
+}
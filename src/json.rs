@@ -0,0 +1,167 @@
+//! Approximate equality of JSON values, for comparing API responses with golden files.
+//!
+//! Numbers are compared approximately as `f64`, so `1` and `1.0` are equal, while everything
+//! else is compared exactly. Objects are compared by key, regardless of their order, and arrays
+//! element-wise. The first difference is reported along with its location, as a
+//! [JSON pointer](https://tools.ietf.org/html/rfc6901).
+//!
+//! [`serde_json::Value`] also implements [`AbsDiffEq`], [`RelativeEq`] and [`UlpsEq`] in the same
+//! way, so that it can be used with the other macros in this crate.
+//!
+//! [`AbsDiffEq`]: crate::AbsDiffEq
+//! [`RelativeEq`]: crate::RelativeEq
+//! [`UlpsEq`]: crate::UlpsEq
+//!
+//! # Example
+//!
+//! ```rust
+//! #[macro_use]
+//! extern crate approx;
+//! #[macro_use]
+//! extern crate serde_json;
+//!
+//! # fn main() {
+//! let response = json!({ "points": [{ "x": 0.1 + 0.2, "y": 1 }, { "x": 2.0, "y": 3.5 }] });
+//! let golden = json!({ "points": [{ "x": 0.3, "y": 1.0 }, { "x": 2.0, "y": 3.0 }] });
+//!
+//! assert_relative_eq!(response["points"][0], golden["points"][0]);
+//!
+//! let mismatch = approx::json::relative_eq(&response, &golden, 1e-12, 1e-12).unwrap_err();
+//! assert_eq!(mismatch.pointer, "/points/1/y");
+//! # }
+//! ```
+
+use core::fmt;
+use serde_json::Value;
+use std::string::String;
+use std::vec::Vec;
+
+use serde::{Path, Segment};
+use {AbsDiff, Comparator, Relative, Ulps};
+
+/// The first point at which two JSON values were found to differ.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mismatch {
+    /// The JSON pointer to the differing values, which is empty for the values themselves.
+    pub pointer: String,
+    /// The left-hand value, or `None` if it is only present on the right.
+    pub left: Option<Value>,
+    /// The right-hand value, or `None` if it is only present on the left.
+    pub right: Option<Value>,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.pointer.is_empty() {
+            write!(f, "values differ:")?;
+        } else {
+            write!(f, "values differ at `{}`:", self.pointer)?;
+        }
+        match (&self.left, &self.right) {
+            (Some(left), Some(right)) => {
+                write!(f, "\n\n    left  = {}\n    right = {}", left, right)
+            }
+            (Some(left), None) => write!(f, "\n\n    left  = {}\n    right is missing", left),
+            (None, Some(right)) => write!(f, "\n\n    left is missing\n    right = {}", right),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+/// Compare two JSON values, using the supplied [`Comparator`] for numbers.
+pub fn compare<C: Comparator<f64>>(lhs: &Value, rhs: &Value, cmp: C) -> Result<(), Mismatch> {
+    compare_values(lhs, rhs, &cmp, &mut Vec::new())
+}
+
+/// Compare two JSON values, using [`AbsDiffEq::abs_diff_eq`](crate::AbsDiffEq::abs_diff_eq) for numbers.
+#[inline]
+pub fn abs_diff_eq(lhs: &Value, rhs: &Value, epsilon: f64) -> Result<(), Mismatch> {
+    compare(lhs, rhs, AbsDiff::default().epsilon(epsilon))
+}
+
+/// Compare two JSON values, using [`RelativeEq::relative_eq`](crate::RelativeEq::relative_eq) for numbers.
+#[inline]
+pub fn relative_eq(
+    lhs: &Value,
+    rhs: &Value,
+    epsilon: f64,
+    max_relative: f64,
+) -> Result<(), Mismatch> {
+    let cmp = Relative::default()
+        .epsilon(epsilon)
+        .max_relative(max_relative);
+    compare(lhs, rhs, cmp)
+}
+
+/// Compare two JSON values, using [`UlpsEq::ulps_eq`](crate::UlpsEq::ulps_eq) for numbers.
+#[inline]
+pub fn ulps_eq(lhs: &Value, rhs: &Value, epsilon: f64, max_ulps: u32) -> Result<(), Mismatch> {
+    compare(
+        lhs,
+        rhs,
+        Ulps::default().epsilon(epsilon).max_ulps(max_ulps),
+    )
+}
+
+fn compare_values<C: Comparator<f64>>(
+    lhs: &Value,
+    rhs: &Value,
+    cmp: &C,
+    path: &mut Vec<Segment>,
+) -> Result<(), Mismatch> {
+    let equal = match (lhs, rhs) {
+        (Value::Number(left), Value::Number(right)) => match (left.as_f64(), right.as_f64()) {
+            (Some(left), Some(right)) => cmp.eq(&left, &right),
+            _ => left == right,
+        },
+        (Value::Array(left), Value::Array(right)) => {
+            for index in 0..usize::max(left.len(), right.len()) {
+                path.push(Segment::Index(index));
+                compare_entry(left.get(index), right.get(index), cmp, path)?;
+                path.pop();
+            }
+            return Ok(());
+        }
+        (Value::Object(left), Value::Object(right)) => {
+            for (key, value) in left {
+                path.push(Segment::Key(key.clone()));
+                compare_entry(Some(value), right.get(key), cmp, path)?;
+                path.pop();
+            }
+            match right.iter().find(|entry| !left.contains_key(entry.0)) {
+                Some((key, value)) => {
+                    path.push(Segment::Key(key.clone()));
+                    return Err(mismatch(path, None, Some(value)));
+                }
+                None => return Ok(()),
+            }
+        }
+        (left, right) => left == right,
+    };
+
+    if equal {
+        Ok(())
+    } else {
+        Err(mismatch(path, Some(lhs), Some(rhs)))
+    }
+}
+
+fn compare_entry<C: Comparator<f64>>(
+    lhs: Option<&Value>,
+    rhs: Option<&Value>,
+    cmp: &C,
+    path: &mut Vec<Segment>,
+) -> Result<(), Mismatch> {
+    match (lhs, rhs) {
+        (Some(left), Some(right)) => compare_values(left, right, cmp, path),
+        (left, right) => Err(mismatch(path, left, right)),
+    }
+}
+
+fn mismatch(path: &[Segment], left: Option<&Value>, right: Option<&Value>) -> Mismatch {
+    Mismatch {
+        pointer: Path::new(path.to_vec()).json_pointer(),
+        left: left.cloned(),
+        right: right.cloned(),
+    }
+}
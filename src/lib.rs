@@ -43,6 +43,38 @@
 //! # }
 //! ```
 //!
+//! Tolerance-aware ordering comparisons are available through the `*_{le, ge}!` and
+//! `assert_*_{le, ge}!` macros, which succeed if the values are strictly ordered or
+//! approximately equal:
+//!
+//! ```rust
+//! #[macro_use]
+//! extern crate approx;
+//!
+//! # fn main() {
+//! abs_diff_le!(1.0 + 1e-9, 1.0, epsilon = 1e-6);
+//! relative_ge!(1.0, 2.0, max_relative = 0.5);
+//! assert_ulps_le!(0.1 + 0.2, 0.3);
+//! # }
+//! ```
+//!
+//! Similarly, the `*_in_range!` and `assert_*_in_range!` macros check that a value lies within
+//! an inclusive range, allowing either bound to be exceeded by no more than the tolerance:
+//!
+//! ```rust
+//! #[macro_use]
+//! extern crate approx;
+//!
+//! # fn main() {
+//! let p = 0.1 + 0.2 + 0.7;
+//! assert_approx_in_range!(p, 0.0..=1.0);
+//! assert_relative_in_range!(p, 0.0..=1.0, max_relative = 1e-12);
+//! # }
+//! ```
+//!
+//! The `debug_assert_*_{eq, ne}!` macros behave like their `assert_*_{eq, ne}!` counterparts,
+//! but are only checked when debug assertions are enabled, in the same way as `debug_assert_eq!`.
+//!
 //! # Implementing approximate equality for custom types
 //!
 //! The `*Eq` traits allow approximate equalities to be implemented on types, based on the
@@ -155,27 +187,92 @@
 //!   https://docs.oracle.com/cd/E19957-01/806-3568/ncg_goldberg.html)
 
 #![no_std]
-#![allow(clippy::transmute_float_to_int)]
 
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::RangeInclusive;
+
+#[cfg(feature = "std")]
+#[macro_use]
+extern crate std;
+
+#[cfg(feature = "bigdecimal")]
+extern crate bigdecimal;
+#[cfg(feature = "chrono")]
+extern crate chrono;
+#[cfg(feature = "fixed")]
+extern crate fixed;
+#[cfg(feature = "mint")]
+extern crate mint;
+#[cfg(feature = "num-bigint")]
+extern crate num_bigint;
 #[cfg(feature = "num-complex")]
 extern crate num_complex;
+#[cfg(feature = "num-rational")]
+extern crate num_integer;
+#[cfg(feature = "num-rational")]
+extern crate num_rational;
 extern crate num_traits;
+#[cfg(feature = "ordered-float")]
+extern crate ordered_float;
+#[cfg(feature = "rust_decimal")]
+extern crate rust_decimal;
+#[cfg(feature = "serde")]
+extern crate serde as serde_crate;
+#[cfg(feature = "serde_json")]
+extern crate serde_json;
+#[cfg(feature = "time")]
+extern crate time;
 
 mod abs_diff_eq;
+mod comparator;
+#[cfg(feature = "std")]
+mod norm;
+mod periodic_eq;
+#[cfg(feature = "std")]
+mod phase;
 mod relative_eq;
+mod tolerance;
 mod ulps_eq;
 
+pub mod iter;
+#[cfg(feature = "serde_json")]
+pub mod json;
+#[cfg(feature = "std")]
+pub mod matrix;
+pub mod quaternion;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "std")]
+pub mod snapshot;
+pub mod text;
+#[cfg(feature = "std")]
+pub mod unordered;
+
 mod macros;
 
 pub use abs_diff_eq::AbsDiffEq;
+#[doc(hidden)]
+pub use comparator::{__Using, __UsingDebug, __UsingFallback};
+pub use comparator::{
+    all_of, any_of, both, by_magnitude, either, AllOf, AnyOf, Both, ByMagnitude, Comparator, Either,
+};
+#[cfg(feature = "std")]
+pub use norm::{Norm, NormDiff};
+pub use periodic_eq::PeriodicEq;
+#[cfg(all(feature = "std", feature = "num-complex"))]
+pub use phase::{up_to_phase, UpToPhase};
+#[cfg(feature = "std")]
+pub use phase::{up_to_sign, UpToSign};
 pub use relative_eq::RelativeEq;
+pub use tolerance::{ParseToleranceError, Tolerance};
 pub use ulps_eq::UlpsEq;
 
 /// The requisite parameters for testing for approximate equality using a
 /// absolute difference based comparison.
 ///
 /// This is not normally used directly, rather via the
-/// `assert_abs_diff_{eq|ne}!` and `abs_diff_{eq|ne}!` macros.
+/// `assert_abs_diff_{eq|ne|le|ge}!` and `abs_diff_{eq|ne|le|ge}!` macros.
 ///
 /// # Example
 ///
@@ -185,6 +282,7 @@ pub use ulps_eq::UlpsEq;
 ///
 /// AbsDiff::default().eq(&1.0, &1.0);
 /// AbsDiff::default().epsilon(f64::EPSILON).eq(&1.0, &1.0);
+/// AbsDiff::default().epsilon(f64::EPSILON).le(&1.0, &1.0);
 /// ```
 pub struct AbsDiff<A, B = A>
 where
@@ -195,6 +293,41 @@ where
     pub epsilon: A::Epsilon,
 }
 
+impl<A, B> Clone for AbsDiff<A, B>
+where
+    A: AbsDiffEq<B> + ?Sized,
+    A::Epsilon: Clone,
+    B: ?Sized,
+{
+    #[inline]
+    fn clone(&self) -> AbsDiff<A, B> {
+        AbsDiff {
+            epsilon: self.epsilon.clone(),
+        }
+    }
+}
+
+impl<A, B> Copy for AbsDiff<A, B>
+where
+    A: AbsDiffEq<B> + ?Sized,
+    A::Epsilon: Copy,
+    B: ?Sized,
+{
+}
+
+impl<A, B> fmt::Debug for AbsDiff<A, B>
+where
+    A: AbsDiffEq<B> + ?Sized,
+    A::Epsilon: fmt::Debug,
+    B: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AbsDiff")
+            .field("epsilon", &self.epsilon)
+            .finish()
+    }
+}
+
 impl<A, B> Default for AbsDiff<A, B>
 where
     A: AbsDiffEq<B> + ?Sized,
@@ -219,6 +352,25 @@ where
         AbsDiff { epsilon }
     }
 
+    /// Compare the values as periodic quantities with the specified period, using the shortest
+    /// distance between them.
+    ///
+    /// The epsilon is kept if it was replaced, and is otherwise the default for periodic
+    /// comparisons, [`PeriodicEq::default_periodic_epsilon`].
+    #[inline]
+    pub fn period(self, period: A::Epsilon) -> Periodic<A, B>
+    where
+        A: PeriodicEq<B>,
+        A::Epsilon: PartialEq,
+    {
+        let epsilon = if self.epsilon == A::default_epsilon() {
+            A::default_periodic_epsilon()
+        } else {
+            self.epsilon
+        };
+        Periodic { epsilon, period }
+    }
+
     /// Peform the equality comparison
     #[inline]
     #[must_use]
@@ -234,11 +386,57 @@ where
     }
 }
 
+impl<A, B> AbsDiff<A, B>
+where
+    A: AbsDiffEq<B> + PartialOrd<B> + ?Sized,
+    B: ?Sized,
+{
+    /// Peform the less-than-or-approximately-equal comparison
+    #[inline]
+    #[must_use]
+    pub fn le(self, lhs: &A, rhs: &B) -> bool {
+        lhs < rhs || self.eq(lhs, rhs)
+    }
+
+    /// Peform the greater-than-or-approximately-equal comparison
+    #[inline]
+    #[must_use]
+    pub fn ge(self, lhs: &A, rhs: &B) -> bool {
+        lhs > rhs || self.eq(lhs, rhs)
+    }
+
+    /// Compare the values, treating them as equal if they are within the tolerance.
+    ///
+    /// Returns `None` if the values are not approximately equal and cannot be ordered, for
+    /// example when one of them is NaN.
+    #[inline]
+    #[must_use]
+    pub fn approx_cmp(self, lhs: &A, rhs: &B) -> Option<Ordering> {
+        if self.eq(lhs, rhs) {
+            Some(Ordering::Equal)
+        } else {
+            lhs.partial_cmp(rhs)
+        }
+    }
+
+    /// Check that the value lies within the inclusive range, allowing either bound to be exceeded
+    /// by no more than the tolerance.
+    #[inline]
+    #[must_use]
+    pub fn in_range(self, value: &A, range: &RangeInclusive<B>) -> bool
+    where
+        A::Epsilon: Clone,
+        B: Sized,
+    {
+        self.clone().ge(value, range.start()) && self.le(value, range.end())
+    }
+}
+
 /// The requisite parameters for testing for approximate equality using a
 /// relative based comparison.
 ///
 /// This is not normally used directly, rather via the
-/// `assert_relative_{eq|ne}!` and `relative_{eq|ne}!` macros.
+/// `assert_relative_{eq|ne|le|ge}!` and `relative_{eq|ne|le|ge}!` macros.
 ///
 /// # Example
 ///
@@ -251,6 +449,7 @@ where
 /// Relative::default().max_relative(1.0).eq(&1.0, &1.0);
 /// Relative::default().epsilon(f64::EPSILON).max_relative(1.0).eq(&1.0, &1.0);
 /// Relative::default().max_relative(1.0).epsilon(f64::EPSILON).eq(&1.0, &1.0);
+/// Relative::default().max_relative(1.0).ge(&1.0, &1.0);
 /// ```
 pub struct Relative<A, B = A>
 where
@@ -263,6 +462,43 @@ where
     pub max_relative: A::Epsilon,
 }
 
+impl<A, B> Clone for Relative<A, B>
+where
+    A: RelativeEq<B> + ?Sized,
+    A::Epsilon: Clone,
+    B: ?Sized,
+{
+    #[inline]
+    fn clone(&self) -> Relative<A, B> {
+        Relative {
+            epsilon: self.epsilon.clone(),
+            max_relative: self.max_relative.clone(),
+        }
+    }
+}
+
+impl<A, B> Copy for Relative<A, B>
+where
+    A: RelativeEq<B> + ?Sized,
+    A::Epsilon: Copy,
+    B: ?Sized,
+{
+}
+
+impl<A, B> fmt::Debug for Relative<A, B>
+where
+    A: RelativeEq<B> + ?Sized,
+    A::Epsilon: fmt::Debug,
+    B: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Relative")
+            .field("epsilon", &self.epsilon)
+            .field("max_relative", &self.max_relative)
+            .finish()
+    }
+}
+
 impl<A, B> Default for Relative<A, B>
 where
     A: RelativeEq<B> + ?Sized,
@@ -312,11 +548,57 @@ where
     }
 }
 
+impl<A, B> Relative<A, B>
+where
+    A: RelativeEq<B> + PartialOrd<B> + ?Sized,
+    B: ?Sized,
+{
+    /// Peform the less-than-or-approximately-equal comparison
+    #[inline]
+    #[must_use]
+    pub fn le(self, lhs: &A, rhs: &B) -> bool {
+        lhs < rhs || self.eq(lhs, rhs)
+    }
+
+    /// Peform the greater-than-or-approximately-equal comparison
+    #[inline]
+    #[must_use]
+    pub fn ge(self, lhs: &A, rhs: &B) -> bool {
+        lhs > rhs || self.eq(lhs, rhs)
+    }
+
+    /// Compare the values, treating them as equal if they are within the tolerance.
+    ///
+    /// Returns `None` if the values are not approximately equal and cannot be ordered, for
+    /// example when one of them is NaN.
+    #[inline]
+    #[must_use]
+    pub fn approx_cmp(self, lhs: &A, rhs: &B) -> Option<Ordering> {
+        if self.eq(lhs, rhs) {
+            Some(Ordering::Equal)
+        } else {
+            lhs.partial_cmp(rhs)
+        }
+    }
+
+    /// Check that the value lies within the inclusive range, allowing either bound to be exceeded
+    /// by no more than the tolerance.
+    #[inline]
+    #[must_use]
+    pub fn in_range(self, value: &A, range: &RangeInclusive<B>) -> bool
+    where
+        A::Epsilon: Clone,
+        B: Sized,
+    {
+        self.clone().ge(value, range.start()) && self.le(value, range.end())
+    }
+}
+
 /// The requisite parameters for testing for approximate equality using an ULPs
 /// based comparison.
 ///
-/// This is not normally used directly, rather via the `assert_ulps_{eq|ne}!`
-/// and `ulps_{eq|ne}!` macros.
+/// This is not normally used directly, rather via the `assert_ulps_{eq|ne|le|ge}!`
+/// and `ulps_{eq|ne|le|ge}!` macros.
 ///
 /// # Example
 ///
@@ -329,6 +611,7 @@ where
 /// Ulps::default().max_ulps(4).eq(&1.0, &1.0);
 /// Ulps::default().epsilon(f64::EPSILON).max_ulps(4).eq(&1.0, &1.0);
 /// Ulps::default().max_ulps(4).epsilon(f64::EPSILON).eq(&1.0, &1.0);
+/// Ulps::default().max_ulps(4).approx_cmp(&1.0, &1.0);
 /// ```
 pub struct Ulps<A, B = A>
 where
@@ -341,6 +624,43 @@ where
     pub max_ulps: u32,
 }
 
+impl<A, B> Clone for Ulps<A, B>
+where
+    A: UlpsEq<B> + ?Sized,
+    A::Epsilon: Clone,
+    B: ?Sized,
+{
+    #[inline]
+    fn clone(&self) -> Ulps<A, B> {
+        Ulps {
+            epsilon: self.epsilon.clone(),
+            max_ulps: self.max_ulps,
+        }
+    }
+}
+
+impl<A, B> Copy for Ulps<A, B>
+where
+    A: UlpsEq<B> + ?Sized,
+    A::Epsilon: Copy,
+    B: ?Sized,
+{
+}
+
+impl<A, B> fmt::Debug for Ulps<A, B>
+where
+    A: UlpsEq<B> + ?Sized,
+    A::Epsilon: fmt::Debug,
+    B: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Ulps")
+            .field("epsilon", &self.epsilon)
+            .field("max_ulps", &self.max_ulps)
+            .finish()
+    }
+}
+
 impl<A, B> Default for Ulps<A, B>
 where
     A: UlpsEq<B> + ?Sized,
@@ -386,3 +706,159 @@ where
         A::ulps_ne(lhs, rhs, self.epsilon, self.max_ulps)
     }
 }
+
+impl<A, B> Ulps<A, B>
+where
+    A: UlpsEq<B> + PartialOrd<B> + ?Sized,
+    B: ?Sized,
+{
+    /// Peform the less-than-or-approximately-equal comparison
+    #[inline]
+    #[must_use]
+    pub fn le(self, lhs: &A, rhs: &B) -> bool {
+        lhs < rhs || self.eq(lhs, rhs)
+    }
+
+    /// Peform the greater-than-or-approximately-equal comparison
+    #[inline]
+    #[must_use]
+    pub fn ge(self, lhs: &A, rhs: &B) -> bool {
+        lhs > rhs || self.eq(lhs, rhs)
+    }
+
+    /// Compare the values, treating them as equal if they are within the tolerance.
+    ///
+    /// Returns `None` if the values are not approximately equal and cannot be ordered, for
+    /// example when one of them is NaN.
+    #[inline]
+    #[must_use]
+    pub fn approx_cmp(self, lhs: &A, rhs: &B) -> Option<Ordering> {
+        if self.eq(lhs, rhs) {
+            Some(Ordering::Equal)
+        } else {
+            lhs.partial_cmp(rhs)
+        }
+    }
+
+    /// Check that the value lies within the inclusive range, allowing either bound to be exceeded
+    /// by no more than the tolerance.
+    #[inline]
+    #[must_use]
+    pub fn in_range(self, value: &A, range: &RangeInclusive<B>) -> bool
+    where
+        A::Epsilon: Clone,
+        B: Sized,
+    {
+        self.clone().ge(value, range.start()) && self.le(value, range.end())
+    }
+}
+
+/// The requisite parameters for testing for approximate equality of periodic quantities, such as
+/// angles, using the shortest wrapped distance between them.
+///
+/// This is not normally used directly, rather via the `assert_angle_{eq|ne}!` and
+/// `angle_{eq|ne}!` macros.
+///
+/// # Example
+///
+/// ```rust
+/// use std::f64::consts::PI;
+/// use approx::{AbsDiff, Periodic};
+///
+/// Periodic::default().epsilon(1e-9).eq(&0.0, &(2.0 * PI - 1e-12));
+/// Periodic::default().period(360.0).epsilon(1e-9).eq(&359.5, &-0.5);
+/// AbsDiff::default().epsilon(1e-9).period(2.0 * PI).eq(&PI, &-PI);
+/// ```
+pub struct Periodic<A, B = A>
+where
+    A: PeriodicEq<B> + ?Sized,
+    B: ?Sized,
+{
+    /// The tolerance on the shortest distance between the values.
+    pub epsilon: A::Epsilon,
+    /// The period of the values.
+    pub period: A::Epsilon,
+}
+
+impl<A, B> Clone for Periodic<A, B>
+where
+    A: PeriodicEq<B> + ?Sized,
+    A::Epsilon: Clone,
+    B: ?Sized,
+{
+    #[inline]
+    fn clone(&self) -> Periodic<A, B> {
+        Periodic {
+            epsilon: self.epsilon.clone(),
+            period: self.period.clone(),
+        }
+    }
+}
+
+impl<A, B> Copy for Periodic<A, B>
+where
+    A: PeriodicEq<B> + ?Sized,
+    A::Epsilon: Copy,
+    B: ?Sized,
+{
+}
+
+impl<A, B> fmt::Debug for Periodic<A, B>
+where
+    A: PeriodicEq<B> + ?Sized,
+    A::Epsilon: fmt::Debug,
+    B: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Periodic")
+            .field("epsilon", &self.epsilon)
+            .field("period", &self.period)
+            .finish()
+    }
+}
+
+impl<A, B> Default for Periodic<A, B>
+where
+    A: PeriodicEq<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn default() -> Periodic<A, B> {
+        Periodic {
+            epsilon: A::default_periodic_epsilon(),
+            period: A::default_period(),
+        }
+    }
+}
+
+impl<A, B> Periodic<A, B>
+where
+    A: PeriodicEq<B> + ?Sized,
+    B: ?Sized,
+{
+    /// Replace the epsilon value with the one specified.
+    #[inline]
+    pub fn epsilon(self, epsilon: A::Epsilon) -> Periodic<A, B> {
+        Periodic { epsilon, ..self }
+    }
+
+    /// Replace the period with the one specified.
+    #[inline]
+    pub fn period(self, period: A::Epsilon) -> Periodic<A, B> {
+        Periodic { period, ..self }
+    }
+
+    /// Peform the equality comparison
+    #[inline]
+    #[must_use]
+    pub fn eq(self, lhs: &A, rhs: &B) -> bool {
+        A::periodic_eq(lhs, rhs, self.epsilon, self.period)
+    }
+
+    /// Peform the inequality comparison
+    #[inline]
+    #[must_use]
+    pub fn ne(self, lhs: &A, rhs: &B) -> bool {
+        A::periodic_ne(lhs, rhs, self.epsilon, self.period)
+    }
+}
@@ -78,6 +78,257 @@ macro_rules! ulps_ne {
     };
 }
 
+/// Less than or approximately equal using the absolute difference.
+#[macro_export]
+macro_rules! abs_diff_le {
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::AbsDiff::default()$(.$opt($val))*.le(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::AbsDiff::default()$(.$opt($val))*.le(&$lhs, &$rhs)
+    };
+}
+
+/// Greater than or approximately equal using the absolute difference.
+#[macro_export]
+macro_rules! abs_diff_ge {
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::AbsDiff::default()$(.$opt($val))*.ge(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::AbsDiff::default()$(.$opt($val))*.ge(&$lhs, &$rhs)
+    };
+}
+
+/// Less than or approximately equal using both the absolute difference and relative based comparisons.
+#[macro_export]
+macro_rules! relative_le {
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::Relative::default()$(.$opt($val))*.le(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::Relative::default()$(.$opt($val))*.le(&$lhs, &$rhs)
+    };
+}
+
+/// Greater than or approximately equal using both the absolute difference and relative based comparisons.
+#[macro_export]
+macro_rules! relative_ge {
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::Relative::default()$(.$opt($val))*.ge(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::Relative::default()$(.$opt($val))*.ge(&$lhs, &$rhs)
+    };
+}
+
+/// Less than or approximately equal using both the absolute difference and ULPs (Units in Last Place).
+#[macro_export]
+macro_rules! ulps_le {
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::Ulps::default()$(.$opt($val))*.le(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::Ulps::default()$(.$opt($val))*.le(&$lhs, &$rhs)
+    };
+}
+
+/// Greater than or approximately equal using both the absolute difference and ULPs (Units in Last Place).
+#[macro_export]
+macro_rules! ulps_ge {
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::Ulps::default()$(.$opt($val))*.ge(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::Ulps::default()$(.$opt($val))*.ge(&$lhs, &$rhs)
+    };
+}
+
+/// Approximate inclusion in an inclusive range using the absolute difference.
+#[macro_export]
+macro_rules! abs_diff_in_range {
+    ($value:expr, $range:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::AbsDiff::default()$(.$opt($val))*.in_range(&$value, &$range)
+    };
+    ($value:expr, $range:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::AbsDiff::default()$(.$opt($val))*.in_range(&$value, &$range)
+    };
+}
+
+/// Approximate inclusion in an inclusive range using both the absolute difference and relative based comparisons.
+#[macro_export]
+macro_rules! relative_in_range {
+    ($value:expr, $range:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::Relative::default()$(.$opt($val))*.in_range(&$value, &$range)
+    };
+    ($value:expr, $range:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::Relative::default()$(.$opt($val))*.in_range(&$value, &$range)
+    };
+}
+
+/// Approximate inclusion in an inclusive range using both the absolute difference and ULPs (Units in Last Place).
+#[macro_export]
+macro_rules! ulps_in_range {
+    ($value:expr, $range:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::Ulps::default()$(.$opt($val))*.in_range(&$value, &$range)
+    };
+    ($value:expr, $range:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::Ulps::default()$(.$opt($val))*.in_range(&$value, &$range)
+    };
+}
+
+/// Approximate equality using a [`Comparator`](crate::Comparator).
+#[macro_export]
+macro_rules! approx_eq {
+    ($lhs:expr, $rhs:expr, using = $cmp:expr) => {
+        $crate::Comparator::eq(&$cmp, &$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr, using = $cmp:expr,) => {
+        $crate::Comparator::eq(&$cmp, &$lhs, &$rhs)
+    };
+}
+
+/// Approximate inequality using a [`Comparator`](crate::Comparator).
+#[macro_export]
+macro_rules! approx_ne {
+    ($lhs:expr, $rhs:expr, using = $cmp:expr) => {
+        $crate::Comparator::ne(&$cmp, &$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr, using = $cmp:expr,) => {
+        $crate::Comparator::ne(&$cmp, &$lhs, &$rhs)
+    };
+}
+
+/// Approximate equality of vectors using the norm of their difference.
+#[macro_export]
+macro_rules! norm_eq {
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::NormDiff::default()$(.$opt($val))*.eq(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::NormDiff::default()$(.$opt($val))*.eq(&$lhs, &$rhs)
+    };
+}
+
+/// Approximate inequality of vectors using the norm of their difference.
+#[macro_export]
+macro_rules! norm_ne {
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::NormDiff::default()$(.$opt($val))*.ne(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::NormDiff::default()$(.$opt($val))*.ne(&$lhs, &$rhs)
+    };
+}
+
+/// Approximate equality of matrices using the norm of their difference.
+#[macro_export]
+macro_rules! matrix_eq {
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::matrix::MatrixDiff::default()$(.$opt($val))*.eq(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::matrix::MatrixDiff::default()$(.$opt($val))*.eq(&$lhs, &$rhs)
+    };
+}
+
+/// Approximate inequality of matrices using the norm of their difference.
+#[macro_export]
+macro_rules! matrix_ne {
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::matrix::MatrixDiff::default()$(.$opt($val))*.ne(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::matrix::MatrixDiff::default()$(.$opt($val))*.ne(&$lhs, &$rhs)
+    };
+}
+
+/// Approximate equality of periodic quantities, such as angles, using the shortest distance
+/// between them.
+#[macro_export]
+macro_rules! angle_eq {
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::Periodic::default()$(.$opt($val))*.eq(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::Periodic::default()$(.$opt($val))*.eq(&$lhs, &$rhs)
+    };
+}
+
+/// Approximate inequality of periodic quantities, such as angles, using the shortest distance
+/// between them.
+#[macro_export]
+macro_rules! angle_ne {
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::Periodic::default()$(.$opt($val))*.ne(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::Periodic::default()$(.$opt($val))*.ne(&$lhs, &$rhs)
+    };
+}
+
+/// Approximate equality of quaternions representing the same rotation, comparing their
+/// components using the absolute difference.
+#[macro_export]
+macro_rules! quaternion_eq {
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::Comparator::eq(
+            &$crate::quaternion::double_cover($crate::AbsDiff::default()$(.$opt($val))*),
+            &$lhs,
+            &$rhs,
+        )
+    };
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::Comparator::eq(
+            &$crate::quaternion::double_cover($crate::AbsDiff::default()$(.$opt($val))*),
+            &$lhs,
+            &$rhs,
+        )
+    };
+}
+
+/// Approximate inequality of quaternions representing the same rotation, comparing their
+/// components using the absolute difference.
+#[macro_export]
+macro_rules! quaternion_ne {
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::Comparator::ne(
+            &$crate::quaternion::double_cover($crate::AbsDiff::default()$(.$opt($val))*),
+            &$lhs,
+            &$rhs,
+        )
+    };
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::Comparator::ne(
+            &$crate::quaternion::double_cover($crate::AbsDiff::default()$(.$opt($val))*),
+            &$lhs,
+            &$rhs,
+        )
+    };
+}
+
+/// Approximate equality of quaternions using the angle of the rotation between them.
+#[macro_export]
+macro_rules! rotation_eq {
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::quaternion::RotationAngle::default()$(.$opt($val))*.eq(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::quaternion::RotationAngle::default()$(.$opt($val))*.eq(&$lhs, &$rhs)
+    };
+}
+
+/// Approximate inequality of quaternions using the angle of the rotation between them.
+#[macro_export]
+macro_rules! rotation_ne {
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::quaternion::RotationAngle::default()$(.$opt($val))*.ne(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::quaternion::RotationAngle::default()$(.$opt($val))*.ne(&$lhs, &$rhs)
+    };
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __assert_approx {
@@ -98,6 +349,26 @@ macro_rules! __assert_approx {
             ),
         }
     }};
+    ($eq:ident, $given:expr, $expected:expr, using = $cmp:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::{__UsingDebug, __UsingFallback};
+        match (&($given), &($expected), &($cmp)) {
+            (given, expected, cmp) => assert!(
+                $eq!(*given, *expected, using = *cmp),
+"assert_{}!({}, {}, {})
+
+    left  = {:?}
+    right = {:?}
+{}
+",
+                stringify!($eq),
+                stringify!($given),
+                stringify!($expected),
+                stringify!(using = $cmp),
+                given, expected, (&$crate::__Using(cmp)).__using_line(),
+            ),
+        }
+    }};
     ($eq:ident, $given:expr, $expected:expr, $($opt:ident = $val:expr),+) => {{
         match (&($given), &($expected)) {
             (given, expected) => assert!(
@@ -183,3 +454,847 @@ macro_rules! assert_ulps_ne {
         __assert_approx!(ulps_ne, $given, $expected $(, $opt = $val)*)
     };
 }
+
+/// An assertion that delegates to [`abs_diff_le!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_abs_diff_le {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx!(abs_diff_le, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx!(abs_diff_le, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// An assertion that delegates to [`abs_diff_ge!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_abs_diff_ge {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx!(abs_diff_ge, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx!(abs_diff_ge, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// An assertion that delegates to [`relative_le!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_relative_le {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx!(relative_le, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx!(relative_le, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// An assertion that delegates to [`relative_ge!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_relative_ge {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx!(relative_ge, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx!(relative_ge, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// An assertion that delegates to [`ulps_le!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_ulps_le {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx!(ulps_le, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx!(ulps_le, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// An assertion that delegates to [`ulps_ge!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_ulps_ge {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx!(ulps_ge, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx!(ulps_ge, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// An assertion that delegates to [`approx_eq!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_approx_eq {
+    ($given:expr, $expected:expr, using = $cmp:expr) => {
+        __assert_approx!(approx_eq, $given, $expected, using = $cmp)
+    };
+    ($given:expr, $expected:expr, using = $cmp:expr,) => {
+        __assert_approx!(approx_eq, $given, $expected, using = $cmp)
+    };
+}
+
+/// An assertion that delegates to [`approx_ne!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_approx_ne {
+    ($given:expr, $expected:expr, using = $cmp:expr) => {
+        __assert_approx!(approx_ne, $given, $expected, using = $cmp)
+    };
+    ($given:expr, $expected:expr, using = $cmp:expr,) => {
+        __assert_approx!(approx_ne, $given, $expected, using = $cmp)
+    };
+}
+
+/// An assertion that delegates to [`norm_eq!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_norm_eq {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx!(norm_eq, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx!(norm_eq, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// An assertion that delegates to [`norm_ne!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_norm_ne {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx!(norm_ne, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx!(norm_ne, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// An assertion that delegates to [`angle_eq!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_angle_eq {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx!(angle_eq, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx!(angle_eq, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// An assertion that delegates to [`angle_ne!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_angle_ne {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx!(angle_ne, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx!(angle_ne, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// An assertion that delegates to [`quaternion_eq!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_quaternion_eq {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx!(quaternion_eq, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx!(quaternion_eq, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// An assertion that delegates to [`quaternion_ne!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_quaternion_ne {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx!(quaternion_ne, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx!(quaternion_ne, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// An assertion that delegates to [`rotation_eq!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_rotation_eq {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx!(rotation_eq, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx!(rotation_eq, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// An assertion that delegates to [`rotation_ne!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_rotation_ne {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx!(rotation_ne, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx!(rotation_ne, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_approx_in_range {
+    ($name:ident, $ge:ident, $le:ident, $value:expr, $range:expr) => {{
+        match (&($value), &($range)) {
+            (value, range) => {
+                if !$ge!(*value, *range.start()) {
+                    panic!(
+"assert_{}!({}, {})
+
+    value = {:?}
+    range = {:?}
+
+    below the lower bound by {:?}
+
+",
+                        stringify!($name),
+                        stringify!($value),
+                        stringify!($range),
+                        value, range, *range.start() - *value,
+                    )
+                }
+                if !$le!(*value, *range.end()) {
+                    panic!(
+"assert_{}!({}, {})
+
+    value = {:?}
+    range = {:?}
+
+    above the upper bound by {:?}
+
+",
+                        stringify!($name),
+                        stringify!($value),
+                        stringify!($range),
+                        value, range, *value - *range.end(),
+                    )
+                }
+            }
+        }
+    }};
+    ($name:ident, $ge:ident, $le:ident, $value:expr, $range:expr, $($opt:ident = $val:expr),+) => {{
+        match (&($value), &($range)) {
+            (value, range) => {
+                if !$ge!(*value, *range.start(), $($opt = $val),+) {
+                    panic!(
+"assert_{}!({}, {}, {})
+
+    value = {:?}
+    range = {:?}
+
+    below the lower bound by {:?}
+
+",
+                        stringify!($name),
+                        stringify!($value),
+                        stringify!($range),
+                        stringify!($($opt = $val),+),
+                        value, range, *range.start() - *value,
+                    )
+                }
+                if !$le!(*value, *range.end(), $($opt = $val),+) {
+                    panic!(
+"assert_{}!({}, {}, {})
+
+    value = {:?}
+    range = {:?}
+
+    above the upper bound by {:?}
+
+",
+                        stringify!($name),
+                        stringify!($value),
+                        stringify!($range),
+                        stringify!($($opt = $val),+),
+                        value, range, *value - *range.end(),
+                    )
+                }
+            }
+        }
+    }};
+}
+
+/// An assertion that delegates to [`abs_diff_in_range!`], and panics with the violated bound and the amount
+/// by which it was exceeded on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_abs_diff_in_range {
+    ($value:expr, $range:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx_in_range!(abs_diff_in_range, abs_diff_ge, abs_diff_le, $value, $range $(, $opt = $val)*)
+    };
+    ($value:expr, $range:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx_in_range!(abs_diff_in_range, abs_diff_ge, abs_diff_le, $value, $range $(, $opt = $val)*)
+    };
+}
+
+/// An assertion that a value lies within an inclusive range, up to the absolute difference.
+///
+/// This is the same as [`assert_abs_diff_in_range!`].
+#[macro_export(local_inner_macros)]
+macro_rules! assert_approx_in_range {
+    ($value:expr, $range:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx_in_range!(approx_in_range, abs_diff_ge, abs_diff_le, $value, $range $(, $opt = $val)*)
+    };
+    ($value:expr, $range:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx_in_range!(approx_in_range, abs_diff_ge, abs_diff_le, $value, $range $(, $opt = $val)*)
+    };
+}
+
+/// An assertion that delegates to [`relative_in_range!`], and panics with the violated bound and the amount
+/// by which it was exceeded on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_relative_in_range {
+    ($value:expr, $range:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx_in_range!(relative_in_range, relative_ge, relative_le, $value, $range $(, $opt = $val)*)
+    };
+    ($value:expr, $range:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx_in_range!(relative_in_range, relative_ge, relative_le, $value, $range $(, $opt = $val)*)
+    };
+}
+
+/// An assertion that delegates to [`ulps_in_range!`], and panics with the violated bound and the amount
+/// by which it was exceeded on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_ulps_in_range {
+    ($value:expr, $range:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx_in_range!(ulps_in_range, ulps_ge, ulps_le, $value, $range $(, $opt = $val)*)
+    };
+    ($value:expr, $range:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx_in_range!(ulps_in_range, ulps_ge, ulps_le, $value, $range $(, $opt = $val)*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_approx_mismatch {
+    ($name:ident, $result:expr, $given:expr, $expected:expr) => {{
+        if let Err(mismatch) = $result {
+            panic!(
+"assert_{}!({}, {})
+
+    {}
+
+",
+                stringify!($name),
+                stringify!($given),
+                stringify!($expected),
+                mismatch,
+            )
+        }
+    }};
+    ($name:ident, $result:expr, $given:expr, $expected:expr, $($opt:ident = $val:expr),+) => {{
+        if let Err(mismatch) = $result {
+            panic!(
+"assert_{}!({}, {}, {})
+
+    {}
+
+",
+                stringify!($name),
+                stringify!($given),
+                stringify!($expected),
+                stringify!($($opt = $val),+),
+                mismatch,
+            )
+        }
+    }};
+}
+
+/// An assertion that compares two iterators element-wise using [`abs_diff_eq!`], and panics with the
+/// index of the first mismatch on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_iter_abs_diff_eq {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx_mismatch!(
+            iter_abs_diff_eq,
+            $crate::iter::compare($given, $expected, $crate::AbsDiff::default()$(.$opt($val))*),
+            $given,
+            $expected
+            $(, $opt = $val)*
+        )
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx_mismatch!(
+            iter_abs_diff_eq,
+            $crate::iter::compare($given, $expected, $crate::AbsDiff::default()$(.$opt($val))*),
+            $given,
+            $expected
+            $(, $opt = $val)*
+        )
+    };
+}
+
+/// An assertion that compares two iterators element-wise using [`relative_eq!`], and panics with the
+/// index of the first mismatch on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_iter_relative_eq {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx_mismatch!(
+            iter_relative_eq,
+            $crate::iter::compare($given, $expected, $crate::Relative::default()$(.$opt($val))*),
+            $given,
+            $expected
+            $(, $opt = $val)*
+        )
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx_mismatch!(
+            iter_relative_eq,
+            $crate::iter::compare($given, $expected, $crate::Relative::default()$(.$opt($val))*),
+            $given,
+            $expected
+            $(, $opt = $val)*
+        )
+    };
+}
+
+/// An assertion that compares two iterators element-wise using [`ulps_eq!`], and panics with the
+/// index of the first mismatch on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_iter_ulps_eq {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx_mismatch!(
+            iter_ulps_eq,
+            $crate::iter::compare($given, $expected, $crate::Ulps::default()$(.$opt($val))*),
+            $given,
+            $expected
+            $(, $opt = $val)*
+        )
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx_mismatch!(
+            iter_ulps_eq,
+            $crate::iter::compare($given, $expected, $crate::Ulps::default()$(.$opt($val))*),
+            $given,
+            $expected
+            $(, $opt = $val)*
+        )
+    };
+}
+
+/// An assertion that compares two iterators element-wise using a
+/// [`Comparator`](crate::Comparator), and panics with the index of the first mismatch on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_iter_approx_eq {
+    ($given:expr, $expected:expr, using = $cmp:expr) => {
+        __assert_approx_mismatch!(
+            iter_approx_eq,
+            $crate::iter::compare($given, $expected, $cmp),
+            $given,
+            $expected,
+            using = $cmp
+        )
+    };
+    ($given:expr, $expected:expr, using = $cmp:expr,) => {
+        __assert_approx_mismatch!(
+            iter_approx_eq,
+            $crate::iter::compare($given, $expected, $cmp),
+            $given,
+            $expected,
+            using = $cmp
+        )
+    };
+}
+
+/// An assertion that compares two texts token by token, using [`abs_diff_eq!`] for numbers, and panics with
+/// the line and column of the first mismatch on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_text_abs_diff_eq {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx_mismatch!(
+            text_abs_diff_eq,
+            $crate::text::compare(&$given, &$expected, $crate::AbsDiff::default()$(.$opt($val))*),
+            $given,
+            $expected
+            $(, $opt = $val)*
+        )
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx_mismatch!(
+            text_abs_diff_eq,
+            $crate::text::compare(&$given, &$expected, $crate::AbsDiff::default()$(.$opt($val))*),
+            $given,
+            $expected
+            $(, $opt = $val)*
+        )
+    };
+}
+
+/// An assertion that compares two texts token by token, using [`relative_eq!`] for numbers, and panics with
+/// the line and column of the first mismatch on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_text_relative_eq {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx_mismatch!(
+            text_relative_eq,
+            $crate::text::compare(&$given, &$expected, $crate::Relative::default()$(.$opt($val))*),
+            $given,
+            $expected
+            $(, $opt = $val)*
+        )
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx_mismatch!(
+            text_relative_eq,
+            $crate::text::compare(&$given, &$expected, $crate::Relative::default()$(.$opt($val))*),
+            $given,
+            $expected
+            $(, $opt = $val)*
+        )
+    };
+}
+
+/// An assertion that compares two texts token by token, using [`ulps_eq!`] for numbers, and panics with
+/// the line and column of the first mismatch on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_text_ulps_eq {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx_mismatch!(
+            text_ulps_eq,
+            $crate::text::compare(&$given, &$expected, $crate::Ulps::default()$(.$opt($val))*),
+            $given,
+            $expected
+            $(, $opt = $val)*
+        )
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx_mismatch!(
+            text_ulps_eq,
+            $crate::text::compare(&$given, &$expected, $crate::Ulps::default()$(.$opt($val))*),
+            $given,
+            $expected
+            $(, $opt = $val)*
+        )
+    };
+}
+
+/// An assertion that compares values with a snapshot file element-wise using [`abs_diff_eq!`], and
+/// panics with the differing elements on failure.
+///
+/// The snapshot is written instead if it does not exist, or if `APPROX_UPDATE=1` is set.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_snapshot_abs_diff_eq {
+    ($path:expr, $values:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx_mismatch!(
+            snapshot_abs_diff_eq,
+            $crate::snapshot::compare($path, &$values[..], $crate::AbsDiff::default()$(.$opt($val))*),
+            $path,
+            $values
+            $(, $opt = $val)*
+        )
+    };
+    ($path:expr, $values:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx_mismatch!(
+            snapshot_abs_diff_eq,
+            $crate::snapshot::compare($path, &$values[..], $crate::AbsDiff::default()$(.$opt($val))*),
+            $path,
+            $values
+            $(, $opt = $val)*
+        )
+    };
+}
+
+/// An assertion that compares values with a snapshot file element-wise using [`relative_eq!`], and
+/// panics with the differing elements on failure.
+///
+/// The snapshot is written instead if it does not exist, or if `APPROX_UPDATE=1` is set.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_snapshot_relative_eq {
+    ($path:expr, $values:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx_mismatch!(
+            snapshot_relative_eq,
+            $crate::snapshot::compare($path, &$values[..], $crate::Relative::default()$(.$opt($val))*),
+            $path,
+            $values
+            $(, $opt = $val)*
+        )
+    };
+    ($path:expr, $values:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx_mismatch!(
+            snapshot_relative_eq,
+            $crate::snapshot::compare($path, &$values[..], $crate::Relative::default()$(.$opt($val))*),
+            $path,
+            $values
+            $(, $opt = $val)*
+        )
+    };
+}
+
+/// An assertion that compares values with a snapshot file element-wise using [`ulps_eq!`], and
+/// panics with the differing elements on failure.
+///
+/// The snapshot is written instead if it does not exist, or if `APPROX_UPDATE=1` is set.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_snapshot_ulps_eq {
+    ($path:expr, $values:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx_mismatch!(
+            snapshot_ulps_eq,
+            $crate::snapshot::compare($path, &$values[..], $crate::Ulps::default()$(.$opt($val))*),
+            $path,
+            $values
+            $(, $opt = $val)*
+        )
+    };
+    ($path:expr, $values:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx_mismatch!(
+            snapshot_ulps_eq,
+            $crate::snapshot::compare($path, &$values[..], $crate::Ulps::default()$(.$opt($val))*),
+            $path,
+            $values
+            $(, $opt = $val)*
+        )
+    };
+}
+
+/// An assertion that compares two serializable values using [`approx::serde::compare`], and panics
+/// with the path of the first mismatch on failure.
+///
+/// The options are the methods of [`Tolerance`](crate::Tolerance).
+///
+/// [`approx::serde::compare`]: crate::serde::compare
+#[macro_export(local_inner_macros)]
+macro_rules! assert_serde_eq {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx_mismatch!(
+            serde_eq,
+            $crate::serde::compare(&$given, &$expected, $crate::Tolerance::default()$(.$opt($val))*),
+            $given,
+            $expected
+            $(, $opt = $val)*
+        )
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx_mismatch!(
+            serde_eq,
+            $crate::serde::compare(&$given, &$expected, $crate::Tolerance::default()$(.$opt($val))*),
+            $given,
+            $expected
+            $(, $opt = $val)*
+        )
+    };
+}
+
+/// An assertion that delegates to [`matrix_eq!`], and panics with the norm of the difference and
+/// the location of the largest contribution to it on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_matrix_eq {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx_mismatch!(
+            matrix_eq,
+            $crate::matrix::MatrixDiff::default()$(.$opt($val))*.compare(&$given, &$expected),
+            $given,
+            $expected
+            $(, $opt = $val)*
+        )
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx_mismatch!(
+            matrix_eq,
+            $crate::matrix::MatrixDiff::default()$(.$opt($val))*.compare(&$given, &$expected),
+            $given,
+            $expected
+            $(, $opt = $val)*
+        )
+    };
+}
+
+/// An assertion that delegates to [`matrix_ne!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_matrix_ne {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx!(matrix_ne, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx!(matrix_ne, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// An assertion that compares two collections, ignoring order, using [`abs_diff_eq!`], and panics
+/// with the elements that could not be matched on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_unordered_abs_diff_eq {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx_mismatch!(
+            unordered_abs_diff_eq,
+            $crate::unordered::compare(&$given, &$expected, $crate::AbsDiff::default()$(.$opt($val))*),
+            $given,
+            $expected
+            $(, $opt = $val)*
+        )
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx_mismatch!(
+            unordered_abs_diff_eq,
+            $crate::unordered::compare(&$given, &$expected, $crate::AbsDiff::default()$(.$opt($val))*),
+            $given,
+            $expected
+            $(, $opt = $val)*
+        )
+    };
+}
+
+/// An assertion that compares two collections, ignoring order, using [`relative_eq!`], and panics
+/// with the elements that could not be matched on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_unordered_relative_eq {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx_mismatch!(
+            unordered_relative_eq,
+            $crate::unordered::compare(&$given, &$expected, $crate::Relative::default()$(.$opt($val))*),
+            $given,
+            $expected
+            $(, $opt = $val)*
+        )
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx_mismatch!(
+            unordered_relative_eq,
+            $crate::unordered::compare(&$given, &$expected, $crate::Relative::default()$(.$opt($val))*),
+            $given,
+            $expected
+            $(, $opt = $val)*
+        )
+    };
+}
+
+/// An assertion that compares two collections, ignoring order, using [`ulps_eq!`], and panics
+/// with the elements that could not be matched on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_unordered_ulps_eq {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approx_mismatch!(
+            unordered_ulps_eq,
+            $crate::unordered::compare(&$given, &$expected, $crate::Ulps::default()$(.$opt($val))*),
+            $given,
+            $expected
+            $(, $opt = $val)*
+        )
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approx_mismatch!(
+            unordered_ulps_eq,
+            $crate::unordered::compare(&$given, &$expected, $crate::Ulps::default()$(.$opt($val))*),
+            $given,
+            $expected
+            $(, $opt = $val)*
+        )
+    };
+}
+
+/// An assertion that compares two collections, ignoring order, using a
+/// [`Comparator`](crate::Comparator), and panics with the elements that could not be matched on
+/// failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_unordered_approx_eq {
+    ($given:expr, $expected:expr, using = $cmp:expr) => {
+        __assert_approx_mismatch!(
+            unordered_approx_eq,
+            $crate::unordered::compare(&$given, &$expected, $cmp),
+            $given,
+            $expected,
+            using = $cmp
+        )
+    };
+    ($given:expr, $expected:expr, using = $cmp:expr,) => {
+        __assert_approx_mismatch!(
+            unordered_approx_eq,
+            $crate::unordered::compare(&$given, &$expected, $cmp),
+            $given,
+            $expected,
+            using = $cmp
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __debug_assert_approx {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::__assert_approx!($($arg)*)
+        }
+    };
+}
+
+/// An assertion that delegates to [`abs_diff_eq!`] only in debug builds.
+///
+/// Like `debug_assert_eq!`, this is only checked when debug assertions are enabled, and panics
+/// with the same error as [`assert_abs_diff_eq!`] on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! debug_assert_abs_diff_eq {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __debug_assert_approx!(abs_diff_eq, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __debug_assert_approx!(abs_diff_eq, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// An assertion that delegates to [`abs_diff_ne!`] only in debug builds.
+///
+/// Like `debug_assert_eq!`, this is only checked when debug assertions are enabled, and panics
+/// with the same error as [`assert_abs_diff_ne!`] on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! debug_assert_abs_diff_ne {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __debug_assert_approx!(abs_diff_ne, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __debug_assert_approx!(abs_diff_ne, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// An assertion that delegates to [`relative_eq!`] only in debug builds.
+///
+/// Like `debug_assert_eq!`, this is only checked when debug assertions are enabled, and panics
+/// with the same error as [`assert_relative_eq!`] on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! debug_assert_relative_eq {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __debug_assert_approx!(relative_eq, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __debug_assert_approx!(relative_eq, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// An assertion that delegates to [`relative_ne!`] only in debug builds.
+///
+/// Like `debug_assert_eq!`, this is only checked when debug assertions are enabled, and panics
+/// with the same error as [`assert_relative_ne!`] on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! debug_assert_relative_ne {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __debug_assert_approx!(relative_ne, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __debug_assert_approx!(relative_ne, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// An assertion that delegates to [`ulps_eq!`] only in debug builds.
+///
+/// Like `debug_assert_eq!`, this is only checked when debug assertions are enabled, and panics
+/// with the same error as [`assert_ulps_eq!`] on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! debug_assert_ulps_eq {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __debug_assert_approx!(ulps_eq, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __debug_assert_approx!(ulps_eq, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// An assertion that delegates to [`ulps_ne!`] only in debug builds.
+///
+/// Like `debug_assert_eq!`, this is only checked when debug assertions are enabled, and panics
+/// with the same error as [`assert_ulps_ne!`] on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! debug_assert_ulps_ne {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __debug_assert_approx!(ulps_ne, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __debug_assert_approx!(ulps_ne, $given, $expected $(, $opt = $val)*)
+    };
+}
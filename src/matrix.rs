@@ -0,0 +1,353 @@
+//! Approximate equality of dense matrices, using matrix norms.
+//!
+//! Matrices are represented as views over row-major slices, with a given number of rows and
+//! columns, and a stride between the starts of consecutive rows. Two matrices `A` and `B` are
+//! considered equal if they have the same shape, and `‖A − B‖ ≤ epsilon + max_relative · ‖B‖`.
+//!
+//! # Example
+//!
+//! ```rust
+//! #[macro_use]
+//! extern crate approx;
+//!
+//! use approx::matrix::{Matrix, MatrixNorm};
+//!
+//! # fn main() {
+//! let a = [1.0, 2.0, 3.0, 4.0];
+//! let b = [1.0, 2.0, 3.0, 4.0 + 1e-12];
+//!
+//! assert_matrix_eq!(Matrix::new(&a, 2, 2), Matrix::new(&b, 2, 2), max_relative = 1e-9);
+//! assert_matrix_eq!(
+//!     Matrix::new(&a, 2, 2),
+//!     Matrix::new(&b, 2, 2),
+//!     norm = MatrixNorm::Inf,
+//!     max_relative = 1e-9,
+//! );
+//! # }
+//! ```
+
+use core::fmt;
+
+use num_traits::Float;
+
+use {Norm, RelativeEq};
+
+/// A view of a dense, row-major matrix stored in a slice.
+#[derive(Debug, Clone, Copy)]
+pub struct Matrix<'a, T: 'a> {
+    data: &'a [T],
+    rows: usize,
+    cols: usize,
+    stride: usize,
+}
+
+impl<'a, T: 'a> Matrix<'a, T> {
+    /// A view of a contiguous matrix, with rows stored one after the other.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data` is too short to hold a `rows` by `cols` matrix.
+    #[inline]
+    pub fn new(data: &'a [T], rows: usize, cols: usize) -> Matrix<'a, T> {
+        Matrix::with_stride(data, rows, cols, cols)
+    }
+
+    /// A view of a matrix where each row starts `stride` elements after the previous one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stride` is less than `cols`, or if `data` is too short to hold the matrix.
+    pub fn with_stride(data: &'a [T], rows: usize, cols: usize, stride: usize) -> Matrix<'a, T> {
+        assert!(
+            stride >= cols,
+            "stride {} is less than cols {}",
+            stride,
+            cols
+        );
+        if rows > 0 && cols > 0 {
+            let len = (rows - 1) * stride + cols;
+            assert!(
+                data.len() >= len,
+                "a {}x{} matrix with stride {} needs {} elements, but only {} were supplied",
+                rows,
+                cols,
+                stride,
+                len,
+                data.len(),
+            );
+        }
+        Matrix {
+            data,
+            rows,
+            cols,
+            stride,
+        }
+    }
+
+    /// The number of rows in the matrix.
+    #[inline]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// The number of columns in the matrix.
+    #[inline]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// The element at the given row and column.
+    #[inline]
+    pub fn get(&self, row: usize, col: usize) -> &'a T {
+        assert!(row < self.rows && col < self.cols);
+        &self.data[row * self.stride + col]
+    }
+}
+
+/// A matrix norm, used to measure the size of the difference between two matrices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MatrixNorm {
+    /// The square root of the sum of the squares of the elements.
+    Frobenius,
+    /// The maximum absolute column sum.
+    One,
+    /// The maximum absolute row sum.
+    Inf,
+}
+
+impl Default for MatrixNorm {
+    #[inline]
+    fn default() -> MatrixNorm {
+        MatrixNorm::Frobenius
+    }
+}
+
+/// The part of a matrix that contributed the most to a norm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Location {
+    /// The element at the given row and column.
+    Element(usize, usize),
+    /// The given row.
+    Row(usize),
+    /// The given column.
+    Column(usize),
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Location::Element(row, col) => write!(f, "element ({}, {})", row, col),
+            Location::Row(row) => write!(f, "row {}", row),
+            Location::Column(col) => write!(f, "column {}", col),
+        }
+    }
+}
+
+impl MatrixNorm {
+    /// Compute the norm of a `rows` by `cols` matrix with the supplied elements, along with
+    /// the location that contributed the most to it.
+    fn of<T, F>(self, rows: usize, cols: usize, element: F) -> (T, Location)
+    where
+        T: Float,
+        F: Fn(usize, usize) -> T,
+    {
+        match self {
+            MatrixNorm::Frobenius => {
+                let indices = (0..rows).flat_map(|i| (0..cols).map(move |j| (i, j)));
+                let norm = Norm::L2.of(indices.map(|(i, j)| element(i, j)));
+                let mut worst = (T::zero(), Location::Element(0, 0));
+                for i in 0..rows {
+                    for j in 0..cols {
+                        let abs = element(i, j).abs();
+                        if exceeds(abs, worst.0) {
+                            worst = (abs, Location::Element(i, j));
+                        }
+                    }
+                }
+                (norm, worst.1)
+            }
+            MatrixNorm::One => {
+                let mut worst = (T::zero(), Location::Column(0));
+                for j in 0..cols {
+                    let sum = Norm::L1.of((0..rows).map(|i| element(i, j)));
+                    if exceeds(sum, worst.0) {
+                        worst = (sum, Location::Column(j));
+                    }
+                }
+                worst
+            }
+            MatrixNorm::Inf => {
+                let mut worst = (T::zero(), Location::Row(0));
+                for i in 0..rows {
+                    let sum = Norm::L1.of((0..cols).map(|j| element(i, j)));
+                    if exceeds(sum, worst.0) {
+                        worst = (sum, Location::Row(i));
+                    }
+                }
+                worst
+            }
+        }
+    }
+}
+
+/// Whether `x` should replace `worst` as the largest contribution to a norm, preferring the
+/// first NaN that is encountered.
+#[inline]
+fn exceeds<T: Float>(x: T, worst: T) -> bool {
+    !worst.is_nan() && (x.is_nan() || x > worst)
+}
+
+/// The reason two matrices were found to differ.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mismatch<T> {
+    /// The matrices have different shapes, given as `(rows, cols)`.
+    Shape {
+        /// The shape of the left-hand matrix.
+        left: (usize, usize),
+        /// The shape of the right-hand matrix.
+        right: (usize, usize),
+    },
+    /// The norm of the difference exceeded the tolerance.
+    Norm {
+        /// The norm that was used.
+        norm: MatrixNorm,
+        /// The norm of the difference between the matrices.
+        diff: T,
+        /// The largest difference that would have been tolerated.
+        tolerance: T,
+        /// The part of the difference that contributed the most to its norm.
+        worst: Location,
+    },
+}
+
+impl<T: fmt::Debug> fmt::Display for Mismatch<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Mismatch::Shape { left, right } => write!(
+                f,
+                "shapes differ: left is {}x{}, but right is {}x{}",
+                left.0, left.1, right.0, right.1,
+            ),
+            Mismatch::Norm {
+                norm,
+                ref diff,
+                ref tolerance,
+                worst,
+            } => write!(
+                f,
+                "{:?} norm of the difference is {:?}, but the tolerance is {:?} \
+                 (largest difference in {})",
+                norm, diff, tolerance, worst,
+            ),
+        }
+    }
+}
+
+/// The requisite parameters for testing matrices for approximate equality using the norm of
+/// their difference.
+///
+/// This is not normally used directly, rather via the `assert_matrix_{eq|ne}!` and
+/// `matrix_{eq|ne}!` macros.
+///
+/// # Example
+///
+/// ```rust
+/// use approx::matrix::{Matrix, MatrixDiff, MatrixNorm};
+///
+/// let a = Matrix::new(&[1.0, 2.0, 3.0, 4.0], 2, 2);
+/// let b = Matrix::with_stride(&[1.0, 2.0, 0.0, 3.0, 4.0, 0.0], 2, 2, 3);
+///
+/// MatrixDiff::default().eq(&a, &b);
+/// MatrixDiff::default().norm(MatrixNorm::One).max_relative(1e-9).eq(&a, &b);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct MatrixDiff<T> {
+    /// The norm used to measure the matrices.
+    pub norm: MatrixNorm,
+    /// The absolute tolerance on the norm of the difference.
+    pub epsilon: T,
+    /// The tolerance on the norm of the difference, relative to the norm of the expected matrix.
+    pub max_relative: T,
+}
+
+impl<T> Default for MatrixDiff<T>
+where
+    T: RelativeEq<Epsilon = T>,
+{
+    #[inline]
+    fn default() -> MatrixDiff<T> {
+        MatrixDiff {
+            norm: MatrixNorm::default(),
+            epsilon: T::default_epsilon(),
+            max_relative: T::default_max_relative(),
+        }
+    }
+}
+
+impl<T> MatrixDiff<T>
+where
+    T: Float,
+{
+    /// Replace the norm with the one specified.
+    #[inline]
+    pub fn norm(self, norm: MatrixNorm) -> MatrixDiff<T> {
+        MatrixDiff { norm, ..self }
+    }
+
+    /// Replace the epsilon value with the one specified.
+    #[inline]
+    pub fn epsilon(self, epsilon: T) -> MatrixDiff<T> {
+        MatrixDiff { epsilon, ..self }
+    }
+
+    /// Replace the maximum relative value with the one specified.
+    #[inline]
+    pub fn max_relative(self, max_relative: T) -> MatrixDiff<T> {
+        MatrixDiff {
+            max_relative,
+            ..self
+        }
+    }
+
+    /// Compare the matrices, describing how they differ on failure.
+    pub fn compare(self, lhs: &Matrix<T>, rhs: &Matrix<T>) -> Result<(), Mismatch<T>> {
+        let (rows, cols) = (rhs.rows(), rhs.cols());
+        if (lhs.rows(), lhs.cols()) != (rows, cols) {
+            return Err(Mismatch::Shape {
+                left: (lhs.rows(), lhs.cols()),
+                right: (rows, cols),
+            });
+        }
+
+        let (diff, worst) = self
+            .norm
+            .of(rows, cols, |i, j| *lhs.get(i, j) - *rhs.get(i, j));
+        let (expected, _) = self.norm.of(rows, cols, |i, j| *rhs.get(i, j));
+        let tolerance = self.epsilon + self.max_relative * expected;
+
+        if diff <= tolerance {
+            Ok(())
+        } else {
+            Err(Mismatch::Norm {
+                norm: self.norm,
+                diff,
+                tolerance,
+                worst,
+            })
+        }
+    }
+
+    /// Peform the equality comparison
+    #[inline]
+    #[must_use]
+    pub fn eq(self, lhs: &Matrix<T>, rhs: &Matrix<T>) -> bool {
+        self.compare(lhs, rhs).is_ok()
+    }
+
+    /// Peform the inequality comparison
+    #[inline]
+    #[must_use]
+    pub fn ne(self, lhs: &Matrix<T>, rhs: &Matrix<T>) -> bool {
+        !self.eq(lhs, rhs)
+    }
+}
@@ -0,0 +1,195 @@
+use num_traits::Float;
+
+use {Comparator, RelativeEq};
+
+/// A vector norm, used to measure the size of the difference between two vectors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Norm {
+    /// The sum of the absolute values of the elements.
+    L1,
+    /// The Euclidean norm, computed with scaling to avoid intermediate overflow and underflow.
+    L2,
+    /// The largest absolute value of the elements.
+    Inf,
+}
+
+impl Default for Norm {
+    #[inline]
+    fn default() -> Norm {
+        Norm::L2
+    }
+}
+
+impl Norm {
+    /// Compute the norm of the supplied elements.
+    ///
+    /// The result is NaN if any of the elements are NaN, and infinite if any of the others are
+    /// infinite.
+    pub fn of<T, I>(self, elements: I) -> T
+    where
+        T: Float,
+        I: IntoIterator<Item = T>,
+    {
+        match self {
+            Norm::L1 => {
+                let mut sum = T::zero();
+                for x in elements {
+                    sum = sum + x.abs();
+                }
+                sum
+            }
+            Norm::L2 => {
+                // Scaled sum of squares, as in LAPACK's `xNRM2`. The result is
+                // `scale * sqrt(ssq)`, where `scale` is the largest magnitude seen so far.
+                let mut scale = T::zero();
+                let mut ssq = T::one();
+                let mut infinite = false;
+                for x in elements {
+                    let abs = x.abs();
+                    if abs.is_nan() {
+                        return abs;
+                    } else if abs.is_infinite() {
+                        infinite = true;
+                    } else if abs > T::zero() {
+                        if scale < abs {
+                            let ratio = scale / abs;
+                            ssq = T::one() + ssq * ratio * ratio;
+                            scale = abs;
+                        } else {
+                            let ratio = abs / scale;
+                            ssq = ssq + ratio * ratio;
+                        }
+                    }
+                }
+                if infinite {
+                    T::infinity()
+                } else {
+                    scale * ssq.sqrt()
+                }
+            }
+            Norm::Inf => {
+                let mut max = T::zero();
+                for x in elements {
+                    let abs = x.abs();
+                    if abs.is_nan() {
+                        return abs;
+                    } else if abs > max {
+                        max = abs;
+                    }
+                }
+                max
+            }
+        }
+    }
+}
+
+/// The requisite parameters for testing vectors for approximate equality using the norm of
+/// their difference.
+///
+/// Two vectors `a` and `b` are considered equal if they have the same length, and
+/// `‖a − b‖ ≤ epsilon + max_relative · ‖b‖`. This is more forgiving than an element-wise
+/// comparison when small components have a large relative error, but the vector as a whole is
+/// accurate.
+///
+/// This is not normally used directly, rather via the `assert_norm_{eq|ne}!` and
+/// `norm_{eq|ne}!` macros.
+///
+/// # Example
+///
+/// ```rust
+/// use approx::{Norm, NormDiff};
+///
+/// NormDiff::default().eq(&[1.0, 1e-20], &[1.0, 2e-20]);
+/// NormDiff::default().norm(Norm::L1).max_relative(1e-9).eq(&[1.0, 2.0], &[1.0, 2.0]);
+/// NormDiff::default().norm(Norm::Inf).epsilon(1e-12).eq(&[1.0, 2.0], &[1.0, 2.0]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct NormDiff<T> {
+    /// The norm used to measure the vectors.
+    pub norm: Norm,
+    /// The absolute tolerance on the norm of the difference.
+    pub epsilon: T,
+    /// The tolerance on the norm of the difference, relative to the norm of the expected vector.
+    pub max_relative: T,
+}
+
+impl<T> Default for NormDiff<T>
+where
+    T: RelativeEq<Epsilon = T>,
+{
+    #[inline]
+    fn default() -> NormDiff<T> {
+        NormDiff {
+            norm: Norm::default(),
+            epsilon: T::default_epsilon(),
+            max_relative: T::default_max_relative(),
+        }
+    }
+}
+
+impl<T> NormDiff<T>
+where
+    T: Float,
+{
+    /// Replace the norm with the one specified.
+    #[inline]
+    pub fn norm(self, norm: Norm) -> NormDiff<T> {
+        NormDiff { norm, ..self }
+    }
+
+    /// Replace the epsilon value with the one specified.
+    #[inline]
+    pub fn epsilon(self, epsilon: T) -> NormDiff<T> {
+        NormDiff { epsilon, ..self }
+    }
+
+    /// Replace the maximum relative value with the one specified.
+    #[inline]
+    pub fn max_relative(self, max_relative: T) -> NormDiff<T> {
+        NormDiff {
+            max_relative,
+            ..self
+        }
+    }
+
+    /// Peform the equality comparison
+    #[inline]
+    #[must_use]
+    pub fn eq(self, lhs: &[T], rhs: &[T]) -> bool {
+        if lhs.len() != rhs.len() {
+            return false;
+        }
+
+        // Exactly equal elements are skipped, as the difference of two equal infinities is NaN
+        let diff = self.norm.of(Iterator::zip(lhs.iter(), rhs)
+            .filter(|&(x, y)| x != y)
+            .map(|(&x, &y)| x - y));
+        let expected = self.norm.of(rhs.iter().cloned());
+
+        // An infinite difference is never within the tolerance, which is itself infinite or NaN
+        // when the expected norm is infinite
+        diff == T::zero()
+            || (diff.is_finite() && diff <= self.epsilon + self.max_relative * expected)
+    }
+
+    /// Peform the inequality comparison
+    #[inline]
+    #[must_use]
+    pub fn ne(self, lhs: &[T], rhs: &[T]) -> bool {
+        !self.eq(lhs, rhs)
+    }
+}
+
+impl<T: Float> Comparator<[T]> for NormDiff<T> {
+    #[inline]
+    fn eq(&self, lhs: &[T], rhs: &[T]) -> bool {
+        NormDiff::eq(*self, lhs, rhs)
+    }
+}
+
+impl<T: Float, const N: usize> Comparator<[T; N]> for NormDiff<T> {
+    #[inline]
+    fn eq(&self, lhs: &[T; N], rhs: &[T; N]) -> bool {
+        NormDiff::eq(*self, lhs, rhs)
+    }
+}
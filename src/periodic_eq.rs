@@ -0,0 +1,178 @@
+use core::cell;
+use core::f32::consts::PI as PI_F32;
+use core::f64::consts::PI as PI_F64;
+
+use AbsDiffEq;
+
+/// Equality comparisons between two periodic quantities, such as angles, using the shortest
+/// distance between them when wrapped around the period.
+pub trait PeriodicEq<Rhs = Self>: AbsDiffEq<Rhs>
+where
+    Rhs: ?Sized,
+{
+    /// The default period, used when no `period` value is supplied to the [`angle_eq`] macro.
+    ///
+    /// For floating point numbers this is a full turn in radians, `2π`.
+    fn default_period() -> Self::Epsilon;
+
+    /// The default tolerance, used when no `epsilon` value is supplied to the [`angle_eq`] macro.
+    ///
+    /// For floating point numbers this is the square root of the machine epsilon, which allows
+    /// for the rounding error that builds up when angles are computed, such as when comparing `0`
+    /// with `2π - 1e-12`.
+    fn default_periodic_epsilon() -> Self::Epsilon;
+
+    /// A test for equality that uses the absolute difference between the two values, after
+    /// wrapping it around the period to find the shortest distance between them.
+    ///
+    /// A period of zero compares the values without wrapping, like [`AbsDiffEq::abs_diff_eq`].
+    fn periodic_eq(&self, other: &Rhs, epsilon: Self::Epsilon, period: Self::Epsilon) -> bool;
+
+    /// The inverse of [`PeriodicEq::periodic_eq`].
+    fn periodic_ne(&self, other: &Rhs, epsilon: Self::Epsilon, period: Self::Epsilon) -> bool {
+        !Self::periodic_eq(self, other, epsilon, period)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Base implementations
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+macro_rules! impl_periodic_eq {
+    ($T:ident, $pi:expr, $epsilon:expr) => {
+        impl PeriodicEq for $T {
+            #[inline]
+            fn default_period() -> $T {
+                2.0 * $pi
+            }
+
+            #[inline]
+            fn default_periodic_epsilon() -> $T {
+                $epsilon
+            }
+
+            #[inline]
+            #[allow(unused_imports)]
+            fn periodic_eq(&self, other: &$T, epsilon: $T, period: $T) -> bool {
+                use num_traits::float::FloatCore;
+                let period = $T::abs(period);
+                if period == 0.0 {
+                    return $T::abs(self - other) <= epsilon;
+                }
+                let wrapped = $T::abs(self - other) % period;
+
+                // The shortest distance may be found by going the other way around
+                let distance = if wrapped > period - wrapped {
+                    period - wrapped
+                } else {
+                    wrapped
+                };
+
+                distance <= epsilon
+            }
+        }
+    };
+}
+
+impl_periodic_eq!(f32, PI_F32, 3.452_669_8e-4);
+impl_periodic_eq!(f64, PI_F64, 1.490_116_119_384_765_6e-8);
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Derived implementations
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'a, T: PeriodicEq + ?Sized> PeriodicEq for &'a T {
+    #[inline]
+    fn default_period() -> T::Epsilon {
+        T::default_period()
+    }
+
+    #[inline]
+    fn default_periodic_epsilon() -> T::Epsilon {
+        T::default_periodic_epsilon()
+    }
+
+    #[inline]
+    fn periodic_eq(&self, other: &&'a T, epsilon: T::Epsilon, period: T::Epsilon) -> bool {
+        T::periodic_eq(*self, *other, epsilon, period)
+    }
+}
+
+impl<'a, T: PeriodicEq + ?Sized> PeriodicEq for &'a mut T {
+    #[inline]
+    fn default_period() -> T::Epsilon {
+        T::default_period()
+    }
+
+    #[inline]
+    fn default_periodic_epsilon() -> T::Epsilon {
+        T::default_periodic_epsilon()
+    }
+
+    #[inline]
+    fn periodic_eq(&self, other: &&'a mut T, epsilon: T::Epsilon, period: T::Epsilon) -> bool {
+        T::periodic_eq(*self, *other, epsilon, period)
+    }
+}
+
+impl<T: PeriodicEq + Copy> PeriodicEq for cell::Cell<T> {
+    #[inline]
+    fn default_period() -> T::Epsilon {
+        T::default_period()
+    }
+
+    #[inline]
+    fn default_periodic_epsilon() -> T::Epsilon {
+        T::default_periodic_epsilon()
+    }
+
+    #[inline]
+    fn periodic_eq(&self, other: &cell::Cell<T>, epsilon: T::Epsilon, period: T::Epsilon) -> bool {
+        T::periodic_eq(&self.get(), &other.get(), epsilon, period)
+    }
+}
+
+impl<T: PeriodicEq + ?Sized> PeriodicEq for cell::RefCell<T> {
+    #[inline]
+    fn default_period() -> T::Epsilon {
+        T::default_period()
+    }
+
+    #[inline]
+    fn default_periodic_epsilon() -> T::Epsilon {
+        T::default_periodic_epsilon()
+    }
+
+    #[inline]
+    fn periodic_eq(
+        &self,
+        other: &cell::RefCell<T>,
+        epsilon: T::Epsilon,
+        period: T::Epsilon,
+    ) -> bool {
+        T::periodic_eq(&self.borrow(), &other.borrow(), epsilon, period)
+    }
+}
+
+impl<A, B> PeriodicEq<[B]> for [A]
+where
+    A: PeriodicEq<B>,
+    A::Epsilon: Clone,
+{
+    #[inline]
+    fn default_period() -> A::Epsilon {
+        A::default_period()
+    }
+
+    #[inline]
+    fn default_periodic_epsilon() -> A::Epsilon {
+        A::default_periodic_epsilon()
+    }
+
+    #[inline]
+    fn periodic_eq(&self, other: &[B], epsilon: A::Epsilon, period: A::Epsilon) -> bool {
+        self.len() == other.len()
+            && Iterator::zip(self.iter(), other)
+                .all(|(x, y)| A::periodic_eq(x, y, epsilon.clone(), period.clone()))
+    }
+}
@@ -0,0 +1,138 @@
+use core::ops::Neg;
+#[cfg(feature = "num-complex")]
+use num_complex::Complex;
+#[cfg(feature = "num-complex")]
+use num_traits::{Float, Zero};
+use std::vec::Vec;
+
+use Comparator;
+
+/// A comparator for vectors that are only defined up to their sign, such as real eigenvectors
+/// and singular vectors.
+///
+/// This succeeds if the wrapped slice comparator considers `a ≈ b` or `a ≈ −b`. It is usually
+/// constructed with the [`up_to_sign`] function.
+#[derive(Debug, Clone, Copy)]
+pub struct UpToSign<C>(pub C);
+
+/// Compare vectors up to their sign, using the supplied slice comparator.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate approx;
+///
+/// use approx::Relative;
+///
+/// # fn main() {
+/// let v = [0.6, -0.8];
+/// let w = [-0.6, 0.8];
+///
+/// assert_approx_eq!(v, w, using = approx::up_to_sign(Relative::default()));
+/// assert_approx_eq!(v[..], v[..], using = approx::up_to_sign(Relative::default()));
+/// # }
+/// ```
+#[inline]
+pub fn up_to_sign<C>(cmp: C) -> UpToSign<C> {
+    UpToSign(cmp)
+}
+
+impl<T, C> Comparator<[T]> for UpToSign<C>
+where
+    T: Clone + Neg<Output = T>,
+    C: Comparator<[T]>,
+{
+    fn eq(&self, lhs: &[T], rhs: &[T]) -> bool {
+        if self.0.eq(lhs, rhs) {
+            return true;
+        }
+        let negated: Vec<T> = rhs.iter().cloned().map(Neg::neg).collect();
+        self.0.eq(lhs, &negated)
+    }
+}
+
+impl<T, C, const N: usize> Comparator<[T; N]> for UpToSign<C>
+where
+    T: Clone + Neg<Output = T>,
+    C: Comparator<[T]>,
+{
+    #[inline]
+    fn eq(&self, lhs: &[T; N], rhs: &[T; N]) -> bool {
+        Comparator::<[T]>::eq(self, lhs, rhs)
+    }
+}
+
+/// A comparator for complex vectors that are only defined up to a global phase factor, such as
+/// complex eigenvectors and singular vectors.
+///
+/// This succeeds if the wrapped slice comparator considers `a ≈ e^{iθ}·b`, where the phase `θ`
+/// is estimated as the argument of the inner product `⟨b, a⟩`. This is the phase that minimises
+/// the Euclidean distance between `a` and `e^{iθ}·b`. It is usually constructed with the
+/// [`up_to_phase`] function.
+#[cfg(feature = "num-complex")]
+#[derive(Debug, Clone, Copy)]
+pub struct UpToPhase<C>(pub C);
+
+/// Compare complex vectors up to a global phase factor, using the supplied slice comparator.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate approx;
+/// extern crate num_complex;
+///
+/// use approx::Relative;
+/// use num_complex::Complex;
+///
+/// # fn main() {
+/// let v = [Complex::new(0.6, 0.0), Complex::new(0.0, 0.8)];
+/// let phase = Complex::from_polar(1.0, 1.234);
+/// let w = [v[0] * phase, v[1] * phase];
+///
+/// assert_approx_eq!(v, w, using = approx::up_to_phase(Relative::default().epsilon(1e-12)));
+/// # }
+/// ```
+#[cfg(feature = "num-complex")]
+#[inline]
+pub fn up_to_phase<C>(cmp: C) -> UpToPhase<C> {
+    UpToPhase(cmp)
+}
+
+#[cfg(feature = "num-complex")]
+impl<T, C> Comparator<[Complex<T>]> for UpToPhase<C>
+where
+    T: Float,
+    C: Comparator<[Complex<T>]>,
+{
+    fn eq(&self, lhs: &[Complex<T>], rhs: &[Complex<T>]) -> bool {
+        if lhs.len() != rhs.len() {
+            return false;
+        }
+
+        let mut dot = Complex::<T>::zero();
+        for (a, b) in Iterator::zip(lhs.iter(), rhs) {
+            dot = dot + b.conj() * a;
+        }
+        if dot.is_zero() {
+            return self.0.eq(lhs, rhs);
+        }
+
+        let phase = dot.unscale(dot.norm());
+        let rotated: Vec<Complex<T>> = rhs.iter().map(|b| b * phase).collect();
+        self.0.eq(lhs, &rotated)
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl<T, C, const N: usize> Comparator<[Complex<T>; N]> for UpToPhase<C>
+where
+    T: Float,
+    C: Comparator<[Complex<T>]>,
+{
+    #[inline]
+    fn eq(&self, lhs: &[Complex<T>; N], rhs: &[Complex<T>; N]) -> bool {
+        Comparator::<[Complex<T>]>::eq(self, lhs, rhs)
+    }
+}
@@ -0,0 +1,246 @@
+//! Approximate equality of quaternions representing rotations.
+//!
+//! Unit quaternions double cover the rotation group, so `q` and `−q` represent the same
+//! rotation, even though a component-wise comparison treats them as different. The comparators
+//! in this module take this into account, for quaternions stored as 4-element arrays, 4-tuples,
+//! or `mint::Quaternion`s when the `mint` feature is enabled.
+//!
+//! The comparisons do not depend on whether the scalar part is stored first or last, as long as
+//! both quaternions use the same convention.
+//!
+//! # Example
+//!
+//! ```rust
+//! #[macro_use]
+//! extern crate approx;
+//!
+//! # fn main() {
+//! let q = [0.5, 0.5, 0.5, 0.5];
+//! let r = [-0.5, -0.5, -0.5, -0.5];
+//!
+//! assert_abs_diff_ne!(q[..], r[..]);
+//! assert_quaternion_eq!(q, r);
+//! assert_quaternion_eq!(q, [-0.5, -0.5, -0.5, -0.5 + 1e-9], epsilon = 1e-6);
+//! # }
+//! ```
+
+use core::ops::Neg;
+#[cfg(feature = "std")]
+use num_traits::{Float, One, Zero};
+
+#[cfg(feature = "std")]
+use AbsDiffEq;
+use Comparator;
+#[cfg(feature = "std")]
+use Norm;
+
+/// A type that can be viewed as the four components of a quaternion.
+pub trait Quaternion {
+    /// The type of the components.
+    type Scalar;
+
+    /// The components of the quaternion, in storage order.
+    fn to_array(&self) -> [Self::Scalar; 4];
+}
+
+impl<T: Clone> Quaternion for [T; 4] {
+    type Scalar = T;
+
+    #[inline]
+    fn to_array(&self) -> [T; 4] {
+        self.clone()
+    }
+}
+
+impl<T: Clone> Quaternion for (T, T, T, T) {
+    type Scalar = T;
+
+    #[inline]
+    fn to_array(&self) -> [T; 4] {
+        [
+            self.0.clone(),
+            self.1.clone(),
+            self.2.clone(),
+            self.3.clone(),
+        ]
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<T: Clone> Quaternion for mint::Quaternion<T> {
+    type Scalar = T;
+
+    #[inline]
+    fn to_array(&self) -> [T; 4] {
+        [
+            self.v.x.clone(),
+            self.v.y.clone(),
+            self.v.z.clone(),
+            self.s.clone(),
+        ]
+    }
+}
+
+impl<Q: Quaternion + ?Sized> Quaternion for &Q {
+    type Scalar = Q::Scalar;
+
+    #[inline]
+    fn to_array(&self) -> [Q::Scalar; 4] {
+        Q::to_array(*self)
+    }
+}
+
+/// A comparator for quaternions that succeeds if the wrapped component comparator considers
+/// `q ≈ r` or `q ≈ −r` component-wise.
+///
+/// This is usually constructed with the [`double_cover`] function.
+#[derive(Debug, Clone, Copy)]
+pub struct DoubleCover<C>(pub C);
+
+/// Compare quaternions up to their sign, using the supplied component comparator.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate approx;
+///
+/// use approx::quaternion::double_cover;
+/// use approx::Relative;
+///
+/// # fn main() {
+/// let cmp = double_cover(Relative::default().max_relative(1e-9));
+/// assert_approx_eq!([0.0, 0.6, 0.0, 0.8], [0.0, -0.6, 0.0, -0.8], using = cmp);
+/// # }
+/// ```
+#[inline]
+pub fn double_cover<C>(cmp: C) -> DoubleCover<C> {
+    DoubleCover(cmp)
+}
+
+impl<Q, C> Comparator<Q> for DoubleCover<C>
+where
+    Q: Quaternion,
+    Q::Scalar: Neg<Output = Q::Scalar>,
+    C: Comparator<Q::Scalar>,
+{
+    fn eq(&self, lhs: &Q, rhs: &Q) -> bool {
+        let lhs = lhs.to_array();
+        let rhs = rhs.to_array();
+
+        Iterator::zip(lhs.iter(), &rhs).all(|(x, y)| self.0.eq(x, y))
+            || Iterator::zip(lhs.iter(), rhs).all(|(x, y)| self.0.eq(x, &-y))
+    }
+}
+
+/// The angle of the rotation that takes one quaternion to the other.
+///
+/// The quaternions are normalized first, so they need not be unit quaternions. The result is
+/// in the range `[0, π]`, and is NaN if either quaternion is zero.
+#[cfg(feature = "std")]
+pub fn rotation_angle<Q>(lhs: &Q, rhs: &Q) -> Q::Scalar
+where
+    Q: Quaternion,
+    Q::Scalar: Float,
+{
+    let lhs = lhs.to_array();
+    let rhs = rhs.to_array();
+    let lhs_norm = Norm::L2.of(lhs.iter().cloned());
+    let rhs_norm = Norm::L2.of(rhs.iter().cloned());
+
+    let mut dot = Q::Scalar::zero();
+    for (&x, &y) in Iterator::zip(lhs.iter(), &rhs) {
+        dot = dot + x * y;
+    }
+    let sign = if dot < Q::Scalar::zero() {
+        -Q::Scalar::one()
+    } else {
+        Q::Scalar::one()
+    };
+
+    // The half-angle between the two unit quaternions is `atan2(‖a − b‖, ‖a + b‖)`, which is
+    // better conditioned than taking the arccosine of their dot product.
+    let diff = Iterator::zip(lhs.iter(), &rhs).map(|(&x, &y)| x / lhs_norm - sign * y / rhs_norm);
+    let sum = Iterator::zip(lhs.iter(), &rhs).map(|(&x, &y)| x / lhs_norm + sign * y / rhs_norm);
+    let four = Q::Scalar::one() + Q::Scalar::one() + Q::Scalar::one() + Q::Scalar::one();
+
+    four * Float::atan2(Norm::L2.of(diff), Norm::L2.of(sum))
+}
+
+/// The requisite parameters for testing quaternions for approximate equality using the angle of
+/// the rotation between them.
+///
+/// This is not normally used directly, rather via the `assert_rotation_{eq|ne}!` and
+/// `rotation_{eq|ne}!` macros.
+///
+/// # Example
+///
+/// ```rust
+/// use approx::quaternion::RotationAngle;
+///
+/// RotationAngle::default().eq(&[0.0, 0.0, 0.0, 1.0], &[0.0, 0.0, 0.0, -1.0]);
+/// RotationAngle::default().max_angle(1e-6).eq(&[0.0, 0.0, 0.0, 1.0], &[0.0, 0.0, 1e-9, 1.0]);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy)]
+pub struct RotationAngle<T> {
+    /// The largest rotation angle to tolerate between the quaternions, in radians.
+    pub max_angle: T,
+}
+
+#[cfg(feature = "std")]
+impl<T> Default for RotationAngle<T>
+where
+    T: AbsDiffEq<Epsilon = T>,
+{
+    #[inline]
+    fn default() -> RotationAngle<T> {
+        RotationAngle {
+            max_angle: T::default_epsilon(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> RotationAngle<T>
+where
+    T: Float,
+{
+    /// Replace the maximum angle with the one specified.
+    #[inline]
+    pub fn max_angle(self, max_angle: T) -> RotationAngle<T> {
+        RotationAngle { max_angle }
+    }
+
+    /// Peform the equality comparison
+    #[inline]
+    #[must_use]
+    pub fn eq<Q>(self, lhs: &Q, rhs: &Q) -> bool
+    where
+        Q: Quaternion<Scalar = T>,
+    {
+        rotation_angle(lhs, rhs) <= self.max_angle
+    }
+
+    /// Peform the inequality comparison
+    #[inline]
+    #[must_use]
+    pub fn ne<Q>(self, lhs: &Q, rhs: &Q) -> bool
+    where
+        Q: Quaternion<Scalar = T>,
+    {
+        !self.eq(lhs, rhs)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, Q> Comparator<Q> for RotationAngle<T>
+where
+    T: Float,
+    Q: Quaternion<Scalar = T>,
+{
+    #[inline]
+    fn eq(&self, lhs: &Q, rhs: &Q) -> bool {
+        RotationAngle::eq(*self, lhs, rhs)
+    }
+}
@@ -1,6 +1,35 @@
-use core::{cell, f32, f64};
+#[cfg(feature = "bigdecimal")]
+use bigdecimal::BigDecimal;
+use core::marker::PhantomData;
+use core::time::Duration;
+use core::{cell, cmp, f32, f64, num};
+#[cfg(feature = "fixed")]
+use fixed::types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8};
+#[cfg(feature = "fixed")]
+use fixed::{
+    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
+    FixedU8,
+};
+#[cfg(feature = "serde_json")]
+use json;
+#[cfg(feature = "num-bigint")]
+use num_bigint::{BigInt, BigUint};
 #[cfg(feature = "num-complex")]
 use num_complex::Complex;
+#[cfg(feature = "num-rational")]
+use num_integer::Integer;
+#[cfg(feature = "num-rational")]
+use num_rational::Ratio;
+#[cfg(feature = "num-rational")]
+use num_traits::{CheckedMul, CheckedSub};
+#[cfg(feature = "num-bigint")]
+use num_traits::{Signed, Zero};
+#[cfg(feature = "ordered-float")]
+use ordered_float::{NotNan, OrderedFloat};
+#[cfg(feature = "rust_decimal")]
+use rust_decimal::Decimal;
+#[cfg(feature = "serde_json")]
+use serde_json::Value;
 use AbsDiffEq;
 
 /// Equality comparisons between two numbers using both the absolute difference and
@@ -83,6 +112,151 @@ macro_rules! impl_relative_eq {
 impl_relative_eq!(f32, i32);
 impl_relative_eq!(f64, i64);
 
+impl RelativeEq for () {
+    #[inline]
+    fn default_max_relative() {}
+
+    #[inline]
+    fn relative_eq(&self, _other: &(), _epsilon: (), _max_relative: ()) -> bool {
+        true
+    }
+}
+
+impl<T: ?Sized> RelativeEq for PhantomData<T> {
+    #[inline]
+    fn default_max_relative() {}
+
+    #[inline]
+    fn relative_eq(&self, _other: &PhantomData<T>, _epsilon: (), _max_relative: ()) -> bool {
+        true
+    }
+}
+
+// The relative tolerance of a `Duration` is expressed as a `Duration` too, whose length in seconds
+// is the largest allowed ratio between the difference and the longer of the two durations. For
+// example, a `max_relative` of `Duration::from_millis(1)` tolerates a relative difference of 0.1%,
+// and the default of one nanosecond, the shortest non-zero duration, a relative difference of
+// one part in a billion.
+impl RelativeEq for Duration {
+    #[inline]
+    fn default_max_relative() -> Duration {
+        Duration::from_nanos(1)
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Duration, epsilon: Duration, max_relative: Duration) -> bool {
+        let (largest, abs_diff) = if self > other {
+            (*self, *self - *other)
+        } else {
+            (*other, *other - *self)
+        };
+
+        // For when the durations are really close together
+        if abs_diff <= epsilon {
+            return true;
+        }
+
+        // Use a relative difference comparison
+        abs_diff.as_secs_f64() <= largest.as_secs_f64() * max_relative.as_secs_f64()
+    }
+}
+
+// `$U` is the unsigned fixed-point type with the same number of bits as `$T`, which can hold
+// the magnitude of any `$T`
+#[cfg(feature = "fixed")]
+macro_rules! impl_fixed_relative_eq {
+    ($T:ident, $U:ident, $LeEq:ident) => {
+        impl<Frac: $LeEq> RelativeEq for $T<Frac> {
+            #[inline]
+            fn default_max_relative() -> $T<Frac> {
+                $T::ZERO
+            }
+
+            #[inline]
+            fn relative_eq(
+                &self,
+                other: &$T<Frac>,
+                epsilon: $T<Frac>,
+                max_relative: $T<Frac>,
+            ) -> bool {
+                // For when the numbers are really close together
+                if $T::abs_diff_eq(self, other, epsilon) {
+                    return true;
+                }
+
+                // A negative relative tolerance tolerates nothing
+                if max_relative < $T::<Frac>::ZERO {
+                    return false;
+                }
+
+                let abs_diff = $U::<Frac>::from_bits(self.to_bits().abs_diff(other.to_bits()));
+                let abs_self = $U::<Frac>::from_bits(self.to_bits().abs_diff(0));
+                let abs_other = $U::<Frac>::from_bits(other.to_bits().abs_diff(0));
+                let max_relative = $U::<Frac>::from_bits(max_relative.to_bits().abs_diff(0));
+
+                let largest = if abs_other > abs_self {
+                    abs_other
+                } else {
+                    abs_self
+                };
+
+                // Use a relative difference comparison, where an overflowing product can only
+                // exceed the difference
+                largest
+                    .checked_mul(max_relative)
+                    .map_or(true, |tolerance| abs_diff <= tolerance)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "fixed")]
+impl_fixed_relative_eq!(FixedI8, FixedU8, LeEqU8);
+#[cfg(feature = "fixed")]
+impl_fixed_relative_eq!(FixedI16, FixedU16, LeEqU16);
+#[cfg(feature = "fixed")]
+impl_fixed_relative_eq!(FixedI32, FixedU32, LeEqU32);
+#[cfg(feature = "fixed")]
+impl_fixed_relative_eq!(FixedI64, FixedU64, LeEqU64);
+#[cfg(feature = "fixed")]
+impl_fixed_relative_eq!(FixedI128, FixedU128, LeEqU128);
+#[cfg(feature = "fixed")]
+impl_fixed_relative_eq!(FixedU8, FixedU8, LeEqU8);
+#[cfg(feature = "fixed")]
+impl_fixed_relative_eq!(FixedU16, FixedU16, LeEqU16);
+#[cfg(feature = "fixed")]
+impl_fixed_relative_eq!(FixedU32, FixedU32, LeEqU32);
+#[cfg(feature = "fixed")]
+impl_fixed_relative_eq!(FixedU64, FixedU64, LeEqU64);
+#[cfg(feature = "fixed")]
+impl_fixed_relative_eq!(FixedU128, FixedU128, LeEqU128);
+
+#[cfg(feature = "ordered-float")]
+macro_rules! impl_ordered_float_relative_eq {
+    ($Wrapper:ident, $T:ident) => {
+        impl RelativeEq for $Wrapper<$T> {
+            #[inline]
+            fn default_max_relative() -> $T {
+                $T::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &$Wrapper<$T>, epsilon: $T, max_relative: $T) -> bool {
+                $T::relative_eq(&**self, &**other, epsilon, max_relative)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "ordered-float")]
+impl_ordered_float_relative_eq!(OrderedFloat, f32);
+#[cfg(feature = "ordered-float")]
+impl_ordered_float_relative_eq!(OrderedFloat, f64);
+#[cfg(feature = "ordered-float")]
+impl_ordered_float_relative_eq!(NotNan, f32);
+#[cfg(feature = "ordered-float")]
+impl_ordered_float_relative_eq!(NotNan, f64);
+
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 // Derived implementations
 ///////////////////////////////////////////////////////////////////////////////////////////////////
@@ -150,6 +324,31 @@ impl<T: RelativeEq + ?Sized> RelativeEq for cell::RefCell<T> {
     }
 }
 
+macro_rules! impl_wrapper_relative_eq {
+    ($($Wrapper:ident)::+) => {
+        impl<T: RelativeEq> RelativeEq for $($Wrapper)::+<T> {
+            #[inline]
+            fn default_max_relative() -> T::Epsilon {
+                T::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(
+                &self,
+                other: &$($Wrapper)::+<T>,
+                epsilon: T::Epsilon,
+                max_relative: T::Epsilon,
+            ) -> bool {
+                T::relative_eq(&self.0, &other.0, epsilon, max_relative)
+            }
+        }
+    };
+}
+
+impl_wrapper_relative_eq!(num::Wrapping);
+impl_wrapper_relative_eq!(num::Saturating);
+impl_wrapper_relative_eq!(cmp::Reverse);
+
 impl<A, B> RelativeEq<[B]> for [A]
 where
     A: RelativeEq<B>,
@@ -189,3 +388,181 @@ where
             && T::relative_eq(&self.im, &other.im, epsilon, max_relative)
     }
 }
+
+#[cfg(feature = "serde_json")]
+impl RelativeEq for Value {
+    #[inline]
+    fn default_max_relative() -> f64 {
+        f64::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Value, epsilon: f64, max_relative: f64) -> bool {
+        json::relative_eq(self, other, epsilon, max_relative).is_ok()
+    }
+}
+
+#[cfg(feature = "num-rational")]
+impl<T: Clone + Integer + CheckedMul + CheckedSub> RelativeEq for Ratio<T> {
+    #[inline]
+    fn default_max_relative() -> Ratio<T> {
+        Ratio::from_integer(T::zero())
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Ratio<T>, epsilon: Ratio<T>, max_relative: Ratio<T>) -> bool {
+        let abs_diff = match if self > other {
+            self.checked_sub(other)
+        } else {
+            other.checked_sub(self)
+        } {
+            Some(abs_diff) => abs_diff,
+            // The difference overflows only when it is far larger than any sensible tolerance
+            None => return false,
+        };
+
+        // For when the numbers are really close together
+        if abs_diff <= epsilon {
+            return true;
+        }
+
+        // Use a relative difference comparison against the larger magnitude. The values are
+        // scaled before taking their magnitude, as the magnitude of the most negative integer
+        // overflows, and an overflowing product can only exceed the difference.
+        let zero = Ratio::from_integer(T::zero());
+        let within = |value: &Ratio<T>| match value.checked_mul(&max_relative) {
+            Some(tolerance) if tolerance < zero => zero
+                .checked_sub(&abs_diff)
+                .is_some_and(|neg_abs_diff| tolerance <= neg_abs_diff),
+            Some(tolerance) => abs_diff <= tolerance,
+            None => true,
+        };
+        within(self) || within(other)
+    }
+}
+
+// The relative tolerance of an integer is an integer too, so it's only useful for tolerating
+// differences in whole multiples of the larger magnitude. Compare `Ratio<BigInt>` values for
+// finer tolerances.
+#[cfg(feature = "num-bigint")]
+impl RelativeEq for BigInt {
+    #[inline]
+    fn default_max_relative() -> BigInt {
+        BigInt::zero()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &BigInt, epsilon: BigInt, max_relative: BigInt) -> bool {
+        let abs_diff = (self - other).abs();
+
+        // For when the numbers are really close together
+        if abs_diff <= epsilon {
+            return true;
+        }
+
+        // Use a relative difference comparison
+        abs_diff <= cmp::max(self.abs(), other.abs()) * max_relative
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl RelativeEq for BigUint {
+    #[inline]
+    fn default_max_relative() -> BigUint {
+        BigUint::zero()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &BigUint, epsilon: BigUint, max_relative: BigUint) -> bool {
+        let (largest, abs_diff) = if self > other {
+            (self, self - other)
+        } else {
+            (other, other - self)
+        };
+
+        // For when the numbers are really close together
+        if abs_diff <= epsilon {
+            return true;
+        }
+
+        // Use a relative difference comparison
+        abs_diff <= largest * max_relative
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl RelativeEq for Decimal {
+    #[inline]
+    fn default_max_relative() -> Decimal {
+        Decimal::new(1, Decimal::MAX_SCALE)
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Decimal, epsilon: Decimal, max_relative: Decimal) -> bool {
+        let abs_self = self.abs();
+        let abs_other = other.abs();
+        let (smallest, largest) = if abs_other > abs_self {
+            (abs_self, abs_other)
+        } else {
+            (abs_other, abs_self)
+        };
+
+        let abs_diff = match Decimal::checked_sub(*self, *other) {
+            Some(diff) => diff.abs(),
+            // The values have opposite signs and the difference is `smallest + largest`, so
+            // compare `smallest` against `largest * (max_relative - 1)` instead
+            None => {
+                return max_relative > Decimal::ONE
+                    && largest
+                        .checked_mul(max_relative - Decimal::ONE)
+                        .map_or(true, |tolerance| smallest <= tolerance)
+            }
+        };
+
+        // For when the numbers are really close together
+        if abs_diff <= epsilon {
+            return true;
+        }
+
+        // Use a relative difference comparison, where an overflowing product can only exceed the
+        // difference
+        largest
+            .checked_mul(max_relative)
+            .map_or(true, |tolerance| abs_diff <= tolerance)
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl RelativeEq for BigDecimal {
+    #[inline]
+    fn default_max_relative() -> BigDecimal {
+        BigDecimal::new(1.into(), 100)
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &BigDecimal,
+        epsilon: BigDecimal,
+        max_relative: BigDecimal,
+    ) -> bool {
+        let abs_diff = (self - other).abs();
+
+        // For when the numbers are really close together
+        if abs_diff <= epsilon {
+            return true;
+        }
+
+        let abs_self = self.abs();
+        let abs_other = other.abs();
+
+        let largest = if abs_other > abs_self {
+            abs_other
+        } else {
+            abs_self
+        };
+
+        // Use a relative difference comparison
+        abs_diff <= largest * max_relative
+    }
+}
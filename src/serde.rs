@@ -0,0 +1,198 @@
+//! Serde support, for loading tolerances from configuration files and comparing any serializable
+//! values structurally.
+//!
+//! # Comparison parameters
+//!
+//! [`AbsDiff`], [`Relative`] and [`Ulps`] can be serialized and deserialized. Fields that are
+//! missing when deserializing take the default value for the compared type, so a configuration
+//! only needs to mention the tolerances that it changes. Unknown fields are rejected, to catch
+//! misspelled tolerances.
+//!
+//! # Structural comparison
+//!
+//! The functions in this module walk two values through the serde data model, comparing every
+//! floating point number approximately and everything else exactly. This allows comparing types
+//! from other crates that implement `Serialize`, but not [`RelativeEq`]. Structs and maps are
+//! compared by field name and key, sequences element-wise, and `f32` values are compared as
+//! `f32` so that the ULPs and default tolerances match the type. The first difference is
+//! reported along with its [`Path`].
+//!
+//! This requires the `std` feature.
+//!
+//! ```rust
+//! #[macro_use]
+//! extern crate approx;
+//! extern crate serde;
+//!
+//! use serde::{Serialize, Serializer};
+//! use serde::ser::SerializeStruct;
+//!
+//! struct Point {
+//!     x: f64,
+//!     y: f64,
+//! }
+//!
+//! impl Serialize for Point {
+//!     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+//!         let mut state = serializer.serialize_struct("Point", 2)?;
+//!         state.serialize_field("x", &self.x)?;
+//!         state.serialize_field("y", &self.y)?;
+//!         state.end()
+//!     }
+//! }
+//!
+//! # fn main() {
+//! let computed = vec![Point { x: 0.1 + 0.2, y: 1.0 }, Point { x: 2.0, y: 3.0 }];
+//! let expected = vec![Point { x: 0.3, y: 1.0 }, Point { x: 2.0, y: 3.5 }];
+//!
+//! let mismatch = approx::serde::relative_eq(&computed, &expected, 1e-12, 1e-12).unwrap_err();
+//! assert_eq!(mismatch.to_string(), "values differ at `[1].y`:\n\n    left  = 3.0\n    right = 3.5");
+//!
+//! assert_serde_eq!(computed[0], expected[0], max_relative = 1e-12);
+//! # }
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+use serde_crate::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde_crate::ser::{Serialize, SerializeStruct, Serializer};
+
+use {AbsDiff, AbsDiffEq, Relative, RelativeEq, Ulps, UlpsEq};
+
+#[cfg(feature = "std")]
+mod structural;
+
+#[cfg(feature = "std")]
+pub use self::structural::{abs_diff_eq, compare, relative_eq, ulps_eq, Mismatch, Path, Segment};
+
+macro_rules! impl_serde {
+    ($Name:ident, $Trait:ident, $($field:ident: $Field:ty = $default:expr),+) => {
+        impl<A, B> Serialize for $Name<A, B>
+        where
+            A: $Trait<B> + ?Sized,
+            A::Epsilon: Serialize,
+            B: ?Sized,
+        {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let fields = [$(stringify!($field)),+];
+                let mut state = serializer.serialize_struct(stringify!($Name), fields.len())?;
+                $(state.serialize_field(stringify!($field), &self.$field)?;)+
+                state.end()
+            }
+        }
+
+        impl<'de, A, B> Deserialize<'de> for $Name<A, B>
+        where
+            A: $Trait<B> + ?Sized,
+            A::Epsilon: Deserialize<'de>,
+            B: ?Sized,
+        {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                const FIELDS: &[&str] = &[$(stringify!($field)),+];
+
+                #[allow(non_camel_case_types)]
+                enum Field {
+                    $($field),+
+                }
+
+                struct FieldVisitor;
+
+                impl<'de> Visitor<'de> for FieldVisitor {
+                    type Value = Field;
+
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        f.write_str("a field name")
+                    }
+
+                    fn visit_str<E: de::Error>(self, value: &str) -> Result<Field, E> {
+                        match value {
+                            $(stringify!($field) => Ok(Field::$field),)+
+                            _ => Err(E::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+
+                impl<'de> Deserialize<'de> for Field {
+                    fn deserialize<D>(deserializer: D) -> Result<Field, D::Error>
+                    where
+                        D: Deserializer<'de>,
+                    {
+                        deserializer.deserialize_identifier(FieldVisitor)
+                    }
+                }
+
+                struct ParamsVisitor<A: ?Sized, B: ?Sized>(PhantomData<fn(&A, &B)>);
+
+                impl<'de, A, B> Visitor<'de> for ParamsVisitor<A, B>
+                where
+                    A: $Trait<B> + ?Sized,
+                    A::Epsilon: Deserialize<'de>,
+                    B: ?Sized,
+                {
+                    type Value = $Name<A, B>;
+
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        f.write_str(concat!("struct ", stringify!($Name)))
+                    }
+
+                    fn visit_seq<V>(self, mut seq: V) -> Result<$Name<A, B>, V::Error>
+                    where
+                        V: SeqAccess<'de>,
+                    {
+                        let mut index = 0;
+                        $(
+                            let $field: $Field = match seq.next_element()? {
+                                Some(value) => value,
+                                None => return Err(de::Error::invalid_length(index, &self)),
+                            };
+                            index += 1;
+                        )+
+                        let _ = index;
+                        Ok($Name { $($field),+ })
+                    }
+
+                    fn visit_map<V>(self, mut map: V) -> Result<$Name<A, B>, V::Error>
+                    where
+                        V: MapAccess<'de>,
+                    {
+                        $(let mut $field: Option<$Field> = None;)+
+                        while let Some(key) = map.next_key()? {
+                            match key {
+                                $(
+                                    Field::$field => {
+                                        if $field.is_some() {
+                                            let name = stringify!($field);
+                                            return Err(de::Error::duplicate_field(name));
+                                        }
+                                        $field = Some(map.next_value()?);
+                                    }
+                                )+
+                            }
+                        }
+                        Ok($Name { $($field: $field.unwrap_or_else(|| $default)),+ })
+                    }
+                }
+
+                deserializer.deserialize_struct(
+                    stringify!($Name),
+                    FIELDS,
+                    ParamsVisitor(PhantomData),
+                )
+            }
+        }
+    };
+}
+
+impl_serde!(AbsDiff, AbsDiffEq, epsilon: A::Epsilon = A::default_epsilon());
+impl_serde!(
+    Relative,
+    RelativeEq,
+    epsilon: A::Epsilon = A::default_epsilon(),
+    max_relative: A::Epsilon = A::default_max_relative()
+);
+impl_serde!(
+    Ulps,
+    UlpsEq,
+    epsilon: A::Epsilon = A::default_epsilon(),
+    max_ulps: u32 = A::default_max_ulps()
+);
@@ -0,0 +1,690 @@
+use core::convert::TryFrom;
+use core::fmt;
+use serde_crate::ser::{
+    self, Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant,
+    SerializeTuple, SerializeTupleStruct, SerializeTupleVariant, Serializer,
+};
+use std::boxed::Box;
+use std::string::{String, ToString};
+use std::vec::Vec;
+
+use Tolerance;
+
+/// A step from a value to one of its parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Segment {
+    /// A field of a struct or struct variant.
+    Field(&'static str),
+    /// An element of a sequence, tuple or tuple variant.
+    Index(usize),
+    /// The value of a map entry. Keys that are not strings are written as they would be
+    /// displayed in a [`Mismatch`].
+    Key(String),
+}
+
+/// The location of a part of a value, starting from the value itself.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Path {
+    segments: Vec<Segment>,
+}
+
+impl Path {
+    pub(crate) fn new(segments: Vec<Segment>) -> Path {
+        Path { segments }
+    }
+
+    /// The steps from the value to the part, which are empty for the value itself.
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    /// The path as a [JSON pointer](https://tools.ietf.org/html/rfc6901), such as `/points/1/y`.
+    pub fn json_pointer(&self) -> String {
+        let mut pointer = String::new();
+        for segment in &self.segments {
+            pointer.push('/');
+            let token = match *segment {
+                Segment::Field(name) => name.to_string(),
+                Segment::Index(index) => index.to_string(),
+                Segment::Key(ref key) => key.clone(),
+            };
+            pointer.push_str(&token.replace('~', "~0").replace('/', "~1"));
+        }
+        pointer
+    }
+}
+
+// Paths are written like Rust expressions, such as `.points[1].y` or `["key"]`.
+impl fmt::Display for Path {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for segment in &self.segments {
+            match *segment {
+                Segment::Field(name) => write!(f, ".{}", name)?,
+                Segment::Index(index) => write!(f, "[{}]", index)?,
+                Segment::Key(ref key) => write!(f, "[{:?}]", key)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The first difference found between two serializable values.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Mismatch {
+    /// The parts of the values at `path` differ.
+    Value {
+        /// The location of the differing parts.
+        path: Path,
+        /// The left-hand part, or `None` if it is only present on the right.
+        left: Option<String>,
+        /// The right-hand part, or `None` if it is only present on the left.
+        right: Option<String>,
+    },
+    /// One of the values could not be serialized.
+    Serialize(String),
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Mismatch::Value {
+                ref path,
+                ref left,
+                ref right,
+            } => {
+                if path.segments().is_empty() {
+                    write!(f, "values differ:")?;
+                } else {
+                    write!(f, "values differ at `{}`:", path)?;
+                }
+                match (left, right) {
+                    (Some(left), Some(right)) => {
+                        write!(f, "\n\n    left  = {}\n    right = {}", left, right)
+                    }
+                    (Some(left), None) => {
+                        write!(f, "\n\n    left  = {}\n    right is missing", left)
+                    }
+                    (None, Some(right)) => {
+                        write!(f, "\n\n    left is missing\n    right = {}", right)
+                    }
+                    (None, None) => Ok(()),
+                }
+            }
+            Mismatch::Serialize(ref message) => write!(f, "failed to serialize value: {}", message),
+        }
+    }
+}
+
+/// Compare two serializable values, using the supplied [`Tolerance`] for floating point numbers.
+///
+/// Tolerances that are left unset use the defaults for the type of each number.
+pub fn compare<L, R>(lhs: &L, rhs: &R, tolerance: Tolerance) -> Result<(), Mismatch>
+where
+    L: Serialize + ?Sized,
+    R: Serialize + ?Sized,
+{
+    let lhs = lhs
+        .serialize(ValueSerializer)
+        .map_err(|error| Mismatch::Serialize(error.0))?;
+    let rhs = rhs
+        .serialize(ValueSerializer)
+        .map_err(|error| Mismatch::Serialize(error.0))?;
+    compare_values(&lhs, &rhs, &tolerance, &mut Vec::new())
+}
+
+/// Compare two serializable values, using [`AbsDiffEq::abs_diff_eq`] for floating point numbers.
+///
+/// [`AbsDiffEq::abs_diff_eq`]: crate::AbsDiffEq::abs_diff_eq
+#[inline]
+pub fn abs_diff_eq<L, R>(lhs: &L, rhs: &R, epsilon: f64) -> Result<(), Mismatch>
+where
+    L: Serialize + ?Sized,
+    R: Serialize + ?Sized,
+{
+    compare(lhs, rhs, Tolerance::default().epsilon(epsilon))
+}
+
+/// Compare two serializable values, using [`RelativeEq::relative_eq`] for floating point numbers.
+///
+/// [`RelativeEq::relative_eq`]: crate::RelativeEq::relative_eq
+#[inline]
+pub fn relative_eq<L, R>(lhs: &L, rhs: &R, epsilon: f64, max_relative: f64) -> Result<(), Mismatch>
+where
+    L: Serialize + ?Sized,
+    R: Serialize + ?Sized,
+{
+    let tolerance = Tolerance::default()
+        .epsilon(epsilon)
+        .max_relative(max_relative);
+    compare(lhs, rhs, tolerance)
+}
+
+/// Compare two serializable values, using [`UlpsEq::ulps_eq`] for floating point numbers.
+///
+/// [`UlpsEq::ulps_eq`]: crate::UlpsEq::ulps_eq
+#[inline]
+pub fn ulps_eq<L, R>(lhs: &L, rhs: &R, epsilon: f64, max_ulps: u32) -> Result<(), Mismatch>
+where
+    L: Serialize + ?Sized,
+    R: Serialize + ?Sized,
+{
+    compare(
+        lhs,
+        rhs,
+        Tolerance::default().epsilon(epsilon).max_ulps(max_ulps),
+    )
+}
+
+fn compare_values(
+    lhs: &Value,
+    rhs: &Value,
+    tolerance: &Tolerance,
+    path: &mut Vec<Segment>,
+) -> Result<(), Mismatch> {
+    let equal = match (lhs, rhs) {
+        (&Value::F32(left), &Value::F32(right)) => {
+            // Narrow the tolerance, so that ULPs are counted in the precision of the numbers
+            let tolerance = Tolerance {
+                epsilon: tolerance.epsilon.map(|epsilon| epsilon as f32),
+                max_relative: tolerance
+                    .max_relative
+                    .map(|max_relative| max_relative as f32),
+                max_ulps: tolerance.max_ulps,
+            };
+            tolerance.eq(&left, &right)
+        }
+        (&Value::F32(left), &Value::F64(right)) => tolerance.eq(&f64::from(left), &right),
+        (&Value::F64(left), &Value::F32(right)) => tolerance.eq(&left, &f64::from(right)),
+        (&Value::F64(left), &Value::F64(right)) => tolerance.eq(&left, &right),
+        (Value::Option(Some(left)), Value::Option(Some(right))) => {
+            return compare_values(left, right, tolerance, path);
+        }
+        (Value::Variant(left_name, left), Value::Variant(right_name, right))
+            if left_name == right_name =>
+        {
+            return compare_values(left, right, tolerance, path);
+        }
+        (Value::Seq(left), Value::Seq(right)) => {
+            for index in 0..usize::max(left.len(), right.len()) {
+                path.push(Segment::Index(index));
+                compare_entry(left.get(index), right.get(index), tolerance, path)?;
+                path.pop();
+            }
+            return Ok(());
+        }
+        (Value::Map(left), Value::Map(right)) => {
+            return compare_entries(left, right, tolerance, path, |key| match key {
+                Value::Str(key) => Segment::Key(key.clone()),
+                key => Segment::Key(key.to_string()),
+            });
+        }
+        (Value::Struct(left), Value::Struct(right)) => {
+            return compare_entries(left, right, tolerance, path, |&name| Segment::Field(name));
+        }
+        (left, right) => left == right,
+    };
+
+    if equal {
+        Ok(())
+    } else {
+        Err(mismatch(path, Some(lhs), Some(rhs)))
+    }
+}
+
+fn compare_entry(
+    lhs: Option<&Value>,
+    rhs: Option<&Value>,
+    tolerance: &Tolerance,
+    path: &mut Vec<Segment>,
+) -> Result<(), Mismatch> {
+    match (lhs, rhs) {
+        (Some(left), Some(right)) => compare_values(left, right, tolerance, path),
+        (left, right) => Err(mismatch(path, left, right)),
+    }
+}
+
+/// Compare the entries of two maps or structs by key, regardless of their order.
+fn compare_entries<K, F>(
+    lhs: &[(K, Value)],
+    rhs: &[(K, Value)],
+    tolerance: &Tolerance,
+    path: &mut Vec<Segment>,
+    segment: F,
+) -> Result<(), Mismatch>
+where
+    K: PartialEq,
+    F: Fn(&K) -> Segment,
+{
+    fn find<'a, K: PartialEq>(entries: &'a [(K, Value)], key: &K) -> Option<&'a Value> {
+        entries
+            .iter()
+            .find(|entry| entry.0 == *key)
+            .map(|entry| &entry.1)
+    }
+
+    for (key, left) in lhs {
+        path.push(segment(key));
+        compare_entry(Some(left), find(rhs, key), tolerance, path)?;
+        path.pop();
+    }
+    for (key, right) in rhs {
+        if find(lhs, key).is_none() {
+            path.push(segment(key));
+            return Err(mismatch(path, None, Some(right)));
+        }
+    }
+    Ok(())
+}
+
+fn mismatch(path: &[Segment], left: Option<&Value>, right: Option<&Value>) -> Mismatch {
+    Mismatch::Value {
+        path: Path::new(path.to_vec()),
+        left: left.map(Value::to_string),
+        right: right.map(Value::to_string),
+    }
+}
+
+/// A value in the serde data model.
+///
+/// Newtype structs are transparent, unit structs are units, and tuples and tuple structs are
+/// sequences, so that values are compared by their contents rather than their type names.
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Unit,
+    Bool(bool),
+    /// Integers are normalised, so that equal integers of different types are equal.
+    Int(i128),
+    /// Unsigned integers that do not fit into an `i128`.
+    UInt(u128),
+    F32(f32),
+    F64(f64),
+    Char(char),
+    Str(String),
+    Bytes(Vec<u8>),
+    Option(Option<Box<Value>>),
+    Variant(&'static str, Box<Value>),
+    Seq(Vec<Value>),
+    Map(Vec<(Value, Value)>),
+    Struct(Vec<(&'static str, Value)>),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn write_list<T, F>(f: &mut fmt::Formatter, items: &[T], mut write: F) -> fmt::Result
+        where
+            F: FnMut(&mut fmt::Formatter, &T) -> fmt::Result,
+        {
+            for (index, item) in items.iter().enumerate() {
+                if index > 0 {
+                    write!(f, ", ")?;
+                }
+                write(f, item)?;
+            }
+            Ok(())
+        }
+
+        match *self {
+            Value::Unit => write!(f, "()"),
+            Value::Bool(value) => write!(f, "{}", value),
+            Value::Int(value) => write!(f, "{}", value),
+            Value::UInt(value) => write!(f, "{}", value),
+            Value::F32(value) => write!(f, "{:?}", value),
+            Value::F64(value) => write!(f, "{:?}", value),
+            Value::Char(value) => write!(f, "{:?}", value),
+            Value::Str(ref value) => write!(f, "{:?}", value),
+            Value::Bytes(ref value) => write!(f, "{:?}", value),
+            Value::Option(None) => write!(f, "None"),
+            Value::Option(Some(ref value)) => write!(f, "Some({})", value),
+            Value::Variant(name, ref value) => match **value {
+                Value::Unit => write!(f, "{}", name),
+                Value::Seq(_) | Value::Struct(_) => write!(f, "{} {}", name, value),
+                ref value => write!(f, "{}({})", name, value),
+            },
+            Value::Seq(ref values) => {
+                write!(f, "[")?;
+                write_list(f, values, |f, value| write!(f, "{}", value))?;
+                write!(f, "]")
+            }
+            Value::Map(ref entries) => {
+                write!(f, "{{")?;
+                write_list(f, entries, |f, entry| write!(f, "{}: {}", entry.0, entry.1))?;
+                write!(f, "}}")
+            }
+            Value::Struct(ref fields) => {
+                write!(f, "{{ ")?;
+                write_list(f, fields, |f, field| write!(f, "{}: {}", field.0, field.1))?;
+                write!(f, " }}")
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Error(String);
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: fmt::Display>(message: T) -> Error {
+        Error(message.to_string())
+    }
+}
+
+/// Serializes values into the [`Value`] tree that is compared.
+struct ValueSerializer;
+
+impl Serializer for ValueSerializer {
+    type Ok = Value;
+    type Error = Error;
+    type SerializeSeq = SeqSerializer;
+    type SerializeTuple = SeqSerializer;
+    type SerializeTupleStruct = SeqSerializer;
+    type SerializeTupleVariant = SeqSerializer;
+    type SerializeMap = MapSerializer;
+    type SerializeStruct = StructSerializer;
+    type SerializeStructVariant = StructSerializer;
+
+    fn serialize_bool(self, value: bool) -> Result<Value, Error> {
+        Ok(Value::Bool(value))
+    }
+
+    fn serialize_i8(self, value: i8) -> Result<Value, Error> {
+        self.serialize_i128(i128::from(value))
+    }
+
+    fn serialize_i16(self, value: i16) -> Result<Value, Error> {
+        self.serialize_i128(i128::from(value))
+    }
+
+    fn serialize_i32(self, value: i32) -> Result<Value, Error> {
+        self.serialize_i128(i128::from(value))
+    }
+
+    fn serialize_i64(self, value: i64) -> Result<Value, Error> {
+        self.serialize_i128(i128::from(value))
+    }
+
+    fn serialize_i128(self, value: i128) -> Result<Value, Error> {
+        Ok(Value::Int(value))
+    }
+
+    fn serialize_u8(self, value: u8) -> Result<Value, Error> {
+        self.serialize_u128(u128::from(value))
+    }
+
+    fn serialize_u16(self, value: u16) -> Result<Value, Error> {
+        self.serialize_u128(u128::from(value))
+    }
+
+    fn serialize_u32(self, value: u32) -> Result<Value, Error> {
+        self.serialize_u128(u128::from(value))
+    }
+
+    fn serialize_u64(self, value: u64) -> Result<Value, Error> {
+        self.serialize_u128(u128::from(value))
+    }
+
+    fn serialize_u128(self, value: u128) -> Result<Value, Error> {
+        Ok(i128::try_from(value).map_or(Value::UInt(value), Value::Int))
+    }
+
+    fn serialize_f32(self, value: f32) -> Result<Value, Error> {
+        Ok(Value::F32(value))
+    }
+
+    fn serialize_f64(self, value: f64) -> Result<Value, Error> {
+        Ok(Value::F64(value))
+    }
+
+    fn serialize_char(self, value: char) -> Result<Value, Error> {
+        Ok(Value::Char(value))
+    }
+
+    fn serialize_str(self, value: &str) -> Result<Value, Error> {
+        Ok(Value::Str(value.to_string()))
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<Value, Error> {
+        Ok(Value::Bytes(value.to_vec()))
+    }
+
+    fn serialize_none(self) -> Result<Value, Error> {
+        Ok(Value::Option(None))
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Value, Error> {
+        Ok(Value::Option(Some(Box::new(value.serialize(self)?))))
+    }
+
+    fn serialize_unit(self) -> Result<Value, Error> {
+        Ok(Value::Unit)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value, Error> {
+        Ok(Value::Unit)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<Value, Error> {
+        Ok(Value::Variant(variant, Box::new(Value::Unit)))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Value, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Value, Error> {
+        Ok(Value::Variant(variant, Box::new(value.serialize(self)?)))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqSerializer, Error> {
+        Ok(SeqSerializer {
+            variant: None,
+            values: Vec::with_capacity(len.unwrap_or(0)),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqSerializer, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SeqSerializer, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SeqSerializer, Error> {
+        Ok(SeqSerializer {
+            variant: Some(variant),
+            values: Vec::with_capacity(len),
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<MapSerializer, Error> {
+        Ok(MapSerializer {
+            entries: Vec::with_capacity(len.unwrap_or(0)),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<StructSerializer, Error> {
+        Ok(StructSerializer {
+            variant: None,
+            fields: Vec::with_capacity(len),
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<StructSerializer, Error> {
+        Ok(StructSerializer {
+            variant: Some(variant),
+            fields: Vec::with_capacity(len),
+        })
+    }
+
+    fn collect_str<T: fmt::Display + ?Sized>(self, value: &T) -> Result<Value, Error> {
+        Ok(Value::Str(value.to_string()))
+    }
+}
+
+struct SeqSerializer {
+    variant: Option<&'static str>,
+    values: Vec<Value>,
+}
+
+impl SeqSerializer {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.values.push(value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Value, Error> {
+        let value = Value::Seq(self.values);
+        Ok(match self.variant {
+            Some(variant) => Value::Variant(variant, Box::new(value)),
+            None => value,
+        })
+    }
+}
+
+macro_rules! impl_serialize_seq {
+    ($($Trait:ident, $method:ident;)+) => {
+        $(
+            impl $Trait for SeqSerializer {
+                type Ok = Value;
+                type Error = Error;
+
+                fn $method<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+                    self.push(value)
+                }
+
+                fn end(self) -> Result<Value, Error> {
+                    self.finish()
+                }
+            }
+        )+
+    };
+}
+
+impl_serialize_seq! {
+    SerializeSeq, serialize_element;
+    SerializeTuple, serialize_element;
+    SerializeTupleStruct, serialize_field;
+    SerializeTupleVariant, serialize_field;
+}
+
+struct MapSerializer {
+    entries: Vec<(Value, Value)>,
+    key: Option<Value>,
+}
+
+impl SerializeMap for MapSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        self.key = Some(key.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| Error("map value serialized before its key".to_string()))?;
+        self.entries.push((key, value.serialize(ValueSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(Value::Map(self.entries))
+    }
+}
+
+struct StructSerializer {
+    variant: Option<&'static str>,
+    fields: Vec<(&'static str, Value)>,
+}
+
+impl StructSerializer {
+    fn push<T: Serialize + ?Sized>(&mut self, name: &'static str, value: &T) -> Result<(), Error> {
+        self.fields.push((name, value.serialize(ValueSerializer)?));
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Value, Error> {
+        let value = Value::Struct(self.fields);
+        Ok(match self.variant {
+            Some(variant) => Value::Variant(variant, Box::new(value)),
+            None => value,
+        })
+    }
+}
+
+impl SerializeStruct for StructSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.push(name, value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        self.finish()
+    }
+}
+
+impl SerializeStructVariant for StructSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.push(name, value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        self.finish()
+    }
+}
@@ -0,0 +1,289 @@
+//! Golden-file snapshots of numeric output, compared approximately.
+//!
+//! A snapshot is a text file with one value per line. The first time that a snapshot is compared,
+//! or whenever the `APPROX_UPDATE` environment variable is set to `1`, the values are written to
+//! the file instead of being compared, so that golden output can be regenerated by running the
+//! tests with `APPROX_UPDATE=1 cargo test`. Blank lines and lines starting with `#` are ignored
+//! when reading a snapshot, so snapshots can be annotated by hand.
+//!
+//! When a comparison fails, every differing element is reported along with its index.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! #[macro_use]
+//! extern crate approx;
+//!
+//! # fn main() {
+//! let spectrum: Vec<f64> = (0..64).map(|i| (i as f64 * 0.1).sin()).collect();
+//! assert_snapshot_relative_eq!("tests/snapshots/spectrum.txt", spectrum, max_relative = 1e-12);
+//! # }
+//! ```
+
+use core::fmt;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::string::{String, ToString};
+use std::vec::Vec;
+
+use {AbsDiff, AbsDiffEq, Comparator, Relative, RelativeEq, Ulps, UlpsEq};
+
+/// The number of differing elements that are shown when a [`Mismatch`] is displayed.
+const MAX_SHOWN: usize = 20;
+
+/// Values with a single-line text encoding that can be stored in a snapshot.
+pub trait Encoding: Sized {
+    /// Encode the value as a single line of text, which [`Encoding::decode`] reads back exactly.
+    fn encode(&self) -> String;
+
+    /// Decode a value from a line written by [`Encoding::encode`].
+    fn decode(line: &str) -> Option<Self>;
+}
+
+macro_rules! impl_float_encoding {
+    ($T:ident) => {
+        impl Encoding for $T {
+            #[inline]
+            fn encode(&self) -> String {
+                // The debug representation is the shortest one that round-trips
+                format!("{:?}", self)
+            }
+
+            #[inline]
+            fn decode(line: &str) -> Option<$T> {
+                line.parse().ok()
+            }
+        }
+    };
+}
+
+impl_float_encoding!(f32);
+impl_float_encoding!(f64);
+
+/// An element that differs between the values and the snapshot.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Difference<T> {
+    /// The index of the element.
+    pub index: usize,
+    /// The element of the compared values, or `None` if there are fewer values than in the
+    /// snapshot.
+    pub actual: Option<T>,
+    /// The element of the snapshot, or `None` if there are more values than in the snapshot.
+    pub expected: Option<T>,
+}
+
+/// The reason that a snapshot comparison failed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Mismatch<T> {
+    /// The values differ from the snapshot.
+    Values {
+        /// The path of the snapshot.
+        path: PathBuf,
+        /// Every differing element, in order.
+        differences: Vec<Difference<T>>,
+    },
+    /// A line of the snapshot could not be decoded.
+    Decode {
+        /// The path of the snapshot.
+        path: PathBuf,
+        /// The line that could not be decoded, starting from one.
+        line: usize,
+    },
+    /// The snapshot could not be read or written.
+    Io {
+        /// The path of the snapshot.
+        path: PathBuf,
+        /// A description of the error.
+        message: String,
+    },
+}
+
+impl<T: fmt::Debug> fmt::Display for Mismatch<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Mismatch::Values {
+                ref path,
+                ref differences,
+            } => {
+                write!(
+                    f,
+                    "{} {} from the snapshot `{}`:\n\n    {:<8}{:<28}expected",
+                    differences.len(),
+                    if differences.len() == 1 {
+                        "element differs"
+                    } else {
+                        "elements differ"
+                    },
+                    path.display(),
+                    "index",
+                    "actual",
+                )?;
+                for difference in differences.iter().take(MAX_SHOWN) {
+                    let actual = difference
+                        .actual
+                        .as_ref()
+                        .map_or("-".to_string(), |actual| format!("{:?}", actual));
+                    let expected = difference
+                        .expected
+                        .as_ref()
+                        .map_or("-".to_string(), |expected| format!("{:?}", expected));
+                    write!(f, "\n    {:<8}{:<28}{}", difference.index, actual, expected)?;
+                }
+                if differences.len() > MAX_SHOWN {
+                    write!(f, "\n    ... and {} more", differences.len() - MAX_SHOWN)?;
+                }
+                write!(
+                    f,
+                    "\n\n    Run with APPROX_UPDATE=1 to update the snapshot."
+                )
+            }
+            Mismatch::Decode { ref path, line } => write!(
+                f,
+                "failed to decode line {} of the snapshot `{}`",
+                line,
+                path.display(),
+            ),
+            Mismatch::Io {
+                ref path,
+                ref message,
+            } => write!(
+                f,
+                "failed to access the snapshot `{}`: {}",
+                path.display(),
+                message,
+            ),
+        }
+    }
+}
+
+/// Compare values with the snapshot at `path` element-wise using the supplied [`Comparator`],
+/// or write the snapshot if it does not exist or `APPROX_UPDATE=1` is set.
+pub fn compare<P, T, C>(path: P, values: &[T], cmp: C) -> Result<(), Mismatch<T>>
+where
+    P: AsRef<Path>,
+    T: Encoding + Clone,
+    C: Comparator<T>,
+{
+    let path = path.as_ref();
+    let io_error = |error: io::Error| Mismatch::Io {
+        path: path.to_path_buf(),
+        message: error.to_string(),
+    };
+
+    let update = env::var_os("APPROX_UPDATE").is_some_and(|update| update == "1");
+    let contents = match fs::read_to_string(path) {
+        Ok(_) if update => return write(path, values).map_err(io_error),
+        Ok(contents) => contents,
+        Err(ref error) if error.kind() == io::ErrorKind::NotFound => {
+            return write(path, values).map_err(io_error);
+        }
+        Err(error) => return Err(io_error(error)),
+    };
+
+    let mut expected = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        match T::decode(line) {
+            Some(value) => expected.push(value),
+            None => {
+                return Err(Mismatch::Decode {
+                    path: path.to_path_buf(),
+                    line: index + 1,
+                })
+            }
+        }
+    }
+
+    let differences: Vec<_> = (0..usize::max(values.len(), expected.len()))
+        .filter_map(|index| {
+            let (actual, expected) = (values.get(index), expected.get(index));
+            match (actual, expected) {
+                (Some(actual), Some(expected)) if cmp.eq(actual, expected) => None,
+                _ => Some(Difference {
+                    index,
+                    actual: actual.cloned(),
+                    expected: expected.cloned(),
+                }),
+            }
+        })
+        .collect();
+
+    if differences.is_empty() {
+        Ok(())
+    } else {
+        Err(Mismatch::Values {
+            path: path.to_path_buf(),
+            differences,
+        })
+    }
+}
+
+/// Compare values with a snapshot element-wise using [`AbsDiffEq::abs_diff_eq`].
+#[inline]
+pub fn abs_diff_eq<P, T>(path: P, values: &[T], epsilon: T::Epsilon) -> Result<(), Mismatch<T>>
+where
+    P: AsRef<Path>,
+    T: Encoding + Clone + AbsDiffEq,
+    T::Epsilon: Clone,
+{
+    compare(path, values, AbsDiff::default().epsilon(epsilon))
+}
+
+/// Compare values with a snapshot element-wise using [`RelativeEq::relative_eq`].
+#[inline]
+pub fn relative_eq<P, T>(
+    path: P,
+    values: &[T],
+    epsilon: T::Epsilon,
+    max_relative: T::Epsilon,
+) -> Result<(), Mismatch<T>>
+where
+    P: AsRef<Path>,
+    T: Encoding + Clone + RelativeEq,
+    T::Epsilon: Clone,
+{
+    let cmp = Relative::default()
+        .epsilon(epsilon)
+        .max_relative(max_relative);
+    compare(path, values, cmp)
+}
+
+/// Compare values with a snapshot element-wise using [`UlpsEq::ulps_eq`].
+#[inline]
+pub fn ulps_eq<P, T>(
+    path: P,
+    values: &[T],
+    epsilon: T::Epsilon,
+    max_ulps: u32,
+) -> Result<(), Mismatch<T>>
+where
+    P: AsRef<Path>,
+    T: Encoding + Clone + UlpsEq,
+    T::Epsilon: Clone,
+{
+    compare(
+        path,
+        values,
+        Ulps::default().epsilon(epsilon).max_ulps(max_ulps),
+    )
+}
+
+fn write<T: Encoding>(path: &Path, values: &[T]) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() {
+            fs::create_dir_all(parent)?;
+        }
+    }
+
+    let mut contents = String::new();
+    for value in values {
+        contents.push_str(&value.encode());
+        contents.push('\n');
+    }
+    fs::write(path, contents)
+}
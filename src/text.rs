@@ -0,0 +1,280 @@
+//! Approximate equality of text containing numbers, for comparing generated reports with golden
+//! output.
+//!
+//! Both texts are split into tokens: numbers, runs of other non-whitespace characters, and line
+//! breaks. Numbers are compared approximately as `f64`, and all other tokens exactly. Whitespace
+//! only separates tokens, so the amount of whitespace between them may differ, allowing numbers
+//! in aligned columns to change width. Line breaks must match, so that the reported line of the
+//! first difference is the same in both texts.
+//!
+//! A number is an optional sign, followed by digits with an optional decimal point, and an
+//! optional exponent. Numbers must start and end at word boundaries, so `x1`, `3rd` and `1.2.3`
+//! are compared exactly, while `x=1.5,` is split into `x=`, `1.5` and `,`.
+//!
+//! # Example
+//!
+//! ```rust
+//! #[macro_use]
+//! extern crate approx;
+//!
+//! # fn main() {
+//! let actual = "iterations: 12\nresidual:   1.00000001e-9\n";
+//! let expected = "iterations: 12\nresidual: 1e-9\n";
+//! assert_text_relative_eq!(actual, expected, max_relative = 1e-6);
+//!
+//! let mismatch = approx::text::relative_eq("x = 1.0, y = 2.5", "x = 1.0, y = 2.0", 0.0, 1e-6)
+//!     .unwrap_err();
+//! assert_eq!((mismatch.line, mismatch.column), (1, 14));
+//! # }
+//! ```
+
+use core::fmt;
+
+use {AbsDiff, Comparator, Relative, Ulps};
+
+/// The first point at which two texts were found to differ.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mismatch<'a> {
+    /// The line of the differing token in the left-hand text, starting from one.
+    pub line: usize,
+    /// The column of the differing token in the left-hand text, in characters, starting from one.
+    pub column: usize,
+    /// The left-hand token, or `None` if the left-hand text ended first.
+    pub left: Option<&'a str>,
+    /// The right-hand token, or `None` if the right-hand text ended first.
+    pub right: Option<&'a str>,
+}
+
+impl<'a> fmt::Display for Mismatch<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "texts differ at line {}, column {}:",
+            self.line, self.column
+        )?;
+        match self.left {
+            Some(left) => write!(f, "\n\n    left  = {:?}", left)?,
+            None => write!(f, "\n\n    left ended")?,
+        }
+        match self.right {
+            Some(right) => write!(f, "\n    right = {:?}", right),
+            None => write!(f, "\n    right ended"),
+        }
+    }
+}
+
+/// Compare two texts token by token, using the supplied [`Comparator`] for numbers.
+pub fn compare<'a, C>(lhs: &'a str, rhs: &'a str, cmp: C) -> Result<(), Mismatch<'a>>
+where
+    C: Comparator<f64>,
+{
+    let mut lhs = Tokens::new(lhs);
+    let mut rhs = Tokens::new(rhs);
+
+    loop {
+        let (left, right) = match (lhs.next(), rhs.next()) {
+            (None, None) => return Ok(()),
+            (Some(left), Some(right)) => {
+                let equal = match (left.number, right.number) {
+                    (Some(x), Some(y)) => cmp.eq(&x, &y),
+                    _ => left.text == right.text,
+                };
+                if equal {
+                    continue;
+                }
+                (Some(left), Some(right))
+            }
+            (left, right) => (left, right),
+        };
+
+        let (line, column) = match left {
+            Some(ref token) => (token.line, token.column),
+            None => (lhs.line, lhs.column),
+        };
+        return Err(Mismatch {
+            line,
+            column,
+            left: left.map(|token| token.text),
+            right: right.map(|token| token.text),
+        });
+    }
+}
+
+/// Compare two texts token by token, using [`AbsDiffEq::abs_diff_eq`] for numbers.
+///
+/// [`AbsDiffEq::abs_diff_eq`]: crate::AbsDiffEq::abs_diff_eq
+#[inline]
+pub fn abs_diff_eq<'a>(lhs: &'a str, rhs: &'a str, epsilon: f64) -> Result<(), Mismatch<'a>> {
+    compare(lhs, rhs, AbsDiff::default().epsilon(epsilon))
+}
+
+/// Compare two texts token by token, using [`RelativeEq::relative_eq`] for numbers.
+///
+/// [`RelativeEq::relative_eq`]: crate::RelativeEq::relative_eq
+#[inline]
+pub fn relative_eq<'a>(
+    lhs: &'a str,
+    rhs: &'a str,
+    epsilon: f64,
+    max_relative: f64,
+) -> Result<(), Mismatch<'a>> {
+    let cmp = Relative::default()
+        .epsilon(epsilon)
+        .max_relative(max_relative);
+    compare(lhs, rhs, cmp)
+}
+
+/// Compare two texts token by token, using [`UlpsEq::ulps_eq`] for numbers.
+///
+/// [`UlpsEq::ulps_eq`]: crate::UlpsEq::ulps_eq
+#[inline]
+pub fn ulps_eq<'a>(
+    lhs: &'a str,
+    rhs: &'a str,
+    epsilon: f64,
+    max_ulps: u32,
+) -> Result<(), Mismatch<'a>> {
+    compare(
+        lhs,
+        rhs,
+        Ulps::default().epsilon(epsilon).max_ulps(max_ulps),
+    )
+}
+
+struct Token<'a> {
+    text: &'a str,
+    /// The value of the token, if it is a number.
+    number: Option<f64>,
+    line: usize,
+    column: usize,
+}
+
+/// Splits a text into numbers, words and line breaks, skipping other whitespace.
+#[derive(Clone)]
+struct Tokens<'a> {
+    rest: &'a str,
+    /// The character before `rest`, which decides whether a number can start.
+    prev: Option<char>,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Tokens<'a> {
+    fn new(text: &'a str) -> Tokens<'a> {
+        Tokens {
+            rest: text,
+            prev: None,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    /// Move past the first `len` bytes of the remaining text.
+    fn advance(&mut self, len: usize) -> &'a str {
+        let (text, rest) = self.rest.split_at(len);
+        for c in text.chars() {
+            if c == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+            self.prev = Some(c);
+        }
+        self.rest = rest;
+        text
+    }
+
+    /// The length of the number at the start of the remaining text, if there is one.
+    fn number_len(&self) -> Option<usize> {
+        if self
+            .prev
+            .is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '.')
+        {
+            return None;
+        }
+
+        let bytes = self.rest.as_bytes();
+        let digits = |start: usize| {
+            bytes[start..]
+                .iter()
+                .take_while(|b| b.is_ascii_digit())
+                .count()
+        };
+
+        let mut len = match bytes.first() {
+            Some(b'+') | Some(b'-') => 1,
+            _ => 0,
+        };
+        let integer = digits(len);
+        len += integer;
+        let mut fraction = 0;
+        if bytes.get(len) == Some(&b'.') {
+            fraction = digits(len + 1);
+            len += 1 + fraction;
+        }
+        if integer + fraction == 0 {
+            return None;
+        }
+        if let Some(b'e') | Some(b'E') = bytes.get(len) {
+            let sign = match bytes.get(len + 1) {
+                Some(b'+') | Some(b'-') => 1,
+                _ => 0,
+            };
+            let exponent = digits(len + 1 + sign);
+            if exponent > 0 {
+                len += 1 + sign + exponent;
+            }
+        }
+
+        let ends_word = match bytes.get(len) {
+            Some(b'.') => !bytes.get(len + 1).is_some_and(u8::is_ascii_digit),
+            Some(_) => !self.rest[len..].starts_with(|c: char| c.is_alphanumeric() || c == '_'),
+            None => true,
+        };
+        if ends_word {
+            Some(len)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        let start = self
+            .rest
+            .find(|c: char| c == '\n' || !c.is_whitespace())
+            .unwrap_or(self.rest.len());
+        self.advance(start);
+
+        let (line, column) = (self.line, self.column);
+        let c = self.rest.chars().next()?;
+        let (len, is_number) = if c == '\n' {
+            (1, false)
+        } else if let Some(len) = self.number_len() {
+            (len, true)
+        } else {
+            // A word ends at whitespace, or where a number could start
+            let mut words = self.clone();
+            words.advance(c.len_utf8());
+            while let Some(c) = words.rest.chars().next() {
+                if c.is_whitespace() || words.number_len().is_some() {
+                    break;
+                }
+                words.advance(c.len_utf8());
+            }
+            (self.rest.len() - words.rest.len(), false)
+        };
+
+        let text = self.advance(len);
+        Some(Token {
+            text,
+            number: if is_number { text.parse().ok() } else { None },
+            line,
+            column,
+        })
+    }
+}
@@ -0,0 +1,250 @@
+use core::fmt;
+use core::str::FromStr;
+
+use {AbsDiffEq, Comparator, RelativeEq, UlpsEq};
+
+/// A combination of absolute, relative and ULPs tolerances, with a compact string syntax.
+///
+/// A tolerance is written as one or more terms joined by `+`, where each term is a number
+/// followed by its unit:
+///
+/// - `1e-12abs`: the absolute difference, as in [`abs_diff_eq!`].
+/// - `1e-9rel`: the relative difference, as in [`relative_eq!`].
+/// - `4ulps`: the units in the last place, as in [`ulps_eq!`].
+///
+/// Two values are considered equal if they are equal within any of the terms, so
+/// `1e-12abs+1e-9rel` accepts values that are within `1e-12` of each other, or within a
+/// relative difference of `1e-9`. When a relative or ULPs tolerance is given without an
+/// absolute tolerance, the default epsilon for the type is used, as with the macros, and
+/// `default` uses only the default epsilon.
+///
+/// Tolerances can be used with the [`approx_eq!`] and [`assert_approx_eq!`] macros via the
+/// `using` option.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate approx;
+///
+/// use approx::Tolerance;
+///
+/// # fn main() {
+/// let tolerance: Tolerance = "1e-12abs+1e-9rel".parse().unwrap();
+/// assert_eq!(tolerance.epsilon, Some(1e-12));
+/// assert_eq!(tolerance.max_relative, Some(1e-9));
+/// assert_eq!(tolerance.to_string(), "1e-12abs+1e-9rel");
+///
+/// assert_approx_eq!(1.0, 1.0 + 1e-10, using = tolerance);
+/// assert_approx_ne!(1.0, 1.0 + 1e-8, using = tolerance);
+/// assert_approx_eq!(1.0f32, 1.0000001, using = "4ulps".parse::<Tolerance<f32>>().unwrap());
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Tolerance<T = f64> {
+    /// The tolerance to use when testing values that are close together.
+    pub epsilon: Option<T>,
+    /// The relative tolerance for testing values that are far-apart.
+    pub max_relative: Option<T>,
+    /// The ULPs to tolerate when testing values that are far-apart.
+    pub max_ulps: Option<u32>,
+}
+
+impl<T> Tolerance<T> {
+    /// Replace the epsilon value with the one specified.
+    #[inline]
+    pub fn epsilon(self, epsilon: T) -> Tolerance<T> {
+        Tolerance {
+            epsilon: Some(epsilon),
+            ..self
+        }
+    }
+
+    /// Replace the maximum relative tolerance with the one specified.
+    #[inline]
+    pub fn max_relative(self, max_relative: T) -> Tolerance<T> {
+        Tolerance {
+            max_relative: Some(max_relative),
+            ..self
+        }
+    }
+
+    /// Replace the max ulps value with the one specified.
+    #[inline]
+    pub fn max_ulps(self, max_ulps: u32) -> Tolerance<T> {
+        Tolerance {
+            max_ulps: Some(max_ulps),
+            ..self
+        }
+    }
+
+    /// Peform the equality comparison
+    #[must_use]
+    pub fn eq<A, B>(&self, lhs: &A, rhs: &B) -> bool
+    where
+        A: RelativeEq<B, Epsilon = T> + UlpsEq<B> + ?Sized,
+        B: ?Sized,
+        T: Clone,
+    {
+        let epsilon = self.epsilon.clone().unwrap_or_else(A::default_epsilon);
+
+        match (self.max_relative.clone(), self.max_ulps) {
+            (None, None) => A::abs_diff_eq(lhs, rhs, epsilon),
+            (Some(max_relative), None) => A::relative_eq(lhs, rhs, epsilon, max_relative),
+            (None, Some(max_ulps)) => A::ulps_eq(lhs, rhs, epsilon, max_ulps),
+            (Some(max_relative), Some(max_ulps)) => {
+                A::relative_eq(lhs, rhs, epsilon.clone(), max_relative)
+                    || A::ulps_eq(lhs, rhs, epsilon, max_ulps)
+            }
+        }
+    }
+
+    /// Peform the inequality comparison
+    #[must_use]
+    pub fn ne<A, B>(&self, lhs: &A, rhs: &B) -> bool
+    where
+        A: RelativeEq<B, Epsilon = T> + UlpsEq<B> + ?Sized,
+        B: ?Sized,
+        T: Clone,
+    {
+        !self.eq(lhs, rhs)
+    }
+}
+
+impl<A, B> Comparator<A, B> for Tolerance<<A as AbsDiffEq<B>>::Epsilon>
+where
+    A: RelativeEq<B> + UlpsEq<B> + ?Sized,
+    A::Epsilon: Clone,
+    B: ?Sized,
+{
+    #[inline]
+    fn eq(&self, lhs: &A, rhs: &B) -> bool {
+        Tolerance::eq(self, lhs, rhs)
+    }
+}
+
+// The terms are written in the order `abs`, `rel`, `ulps`, with the numbers in exponential
+// notation so that small tolerances stay readable.
+impl<T: fmt::LowerExp> fmt::Display for Tolerance<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut separator = "";
+        if let Some(ref epsilon) = self.epsilon {
+            write!(f, "{}{:e}abs", separator, epsilon)?;
+            separator = "+";
+        }
+        if let Some(ref max_relative) = self.max_relative {
+            write!(f, "{}{:e}rel", separator, max_relative)?;
+            separator = "+";
+        }
+        if let Some(max_ulps) = self.max_ulps {
+            write!(f, "{}{}ulps", separator, max_ulps)?;
+            separator = "+";
+        }
+        if separator.is_empty() {
+            // An empty tolerance compares using the default epsilon
+            write!(f, "default")?;
+        }
+        Ok(())
+    }
+}
+
+const UNITS: [&str; 3] = ["abs", "rel", "ulps"];
+
+impl<T: FromStr> FromStr for Tolerance<T> {
+    type Err = ParseToleranceError;
+
+    fn from_str(s: &str) -> Result<Tolerance<T>, ParseToleranceError> {
+        let mut tolerance = Tolerance {
+            epsilon: None,
+            max_relative: None,
+            max_ulps: None,
+        };
+
+        let mut rest = s.trim();
+        if rest.is_empty() {
+            return Err(ParseToleranceError::Empty);
+        }
+        if rest == "default" {
+            return Ok(tolerance);
+        }
+
+        loop {
+            // Numbers never contain the unit names, so the first unit ends the term, even if
+            // the number has a `+` in its exponent
+            let (start, unit) = UNITS
+                .iter()
+                .filter_map(|unit| rest.find(unit).map(|start| (start, *unit)))
+                .min()
+                .ok_or(ParseToleranceError::MissingUnit)?;
+            let value = rest[..start].trim();
+
+            match unit {
+                "abs" if tolerance.epsilon.is_some() => {
+                    return Err(ParseToleranceError::Duplicate(unit))
+                }
+                "abs" => tolerance.epsilon = Some(parse_value(value, unit)?),
+                "rel" if tolerance.max_relative.is_some() => {
+                    return Err(ParseToleranceError::Duplicate(unit))
+                }
+                "rel" => tolerance.max_relative = Some(parse_value(value, unit)?),
+                _ if tolerance.max_ulps.is_some() => {
+                    return Err(ParseToleranceError::Duplicate(unit))
+                }
+                _ => tolerance.max_ulps = Some(parse_value(value, unit)?),
+            }
+
+            rest = rest[start + unit.len()..].trim_start();
+            if rest.is_empty() {
+                return Ok(tolerance);
+            }
+            rest = match rest.strip_prefix('+') {
+                Some(rest) => rest.trim_start(),
+                None => return Err(ParseToleranceError::MissingSeparator),
+            };
+        }
+    }
+}
+
+fn parse_value<T: FromStr>(value: &str, unit: &'static str) -> Result<T, ParseToleranceError> {
+    if value.is_empty() {
+        return Err(ParseToleranceError::MissingValue(unit));
+    }
+    value
+        .parse()
+        .map_err(|_| ParseToleranceError::InvalidValue(unit))
+}
+
+/// An error returned when parsing a [`Tolerance`] fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseToleranceError {
+    /// The string contained no tolerances.
+    Empty,
+    /// A term did not end with one of the units `abs`, `rel` or `ulps`.
+    MissingUnit,
+    /// A unit was not preceded by a number.
+    MissingValue(&'static str),
+    /// The number before a unit could not be parsed.
+    InvalidValue(&'static str),
+    /// A term was followed by something other than `+`.
+    MissingSeparator,
+    /// The same unit was given more than once.
+    Duplicate(&'static str),
+}
+
+impl fmt::Display for ParseToleranceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseToleranceError::Empty => write!(f, "empty tolerance"),
+            ParseToleranceError::MissingUnit => {
+                write!(f, "missing unit, expected `abs`, `rel` or `ulps`")
+            }
+            ParseToleranceError::MissingValue(unit) => write!(f, "missing value for `{}`", unit),
+            ParseToleranceError::InvalidValue(unit) => write!(f, "invalid value for `{}`", unit),
+            ParseToleranceError::MissingSeparator => write!(f, "expected `+` between tolerances"),
+            ParseToleranceError::Duplicate(unit) => write!(f, "`{}` given more than once", unit),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseToleranceError {}
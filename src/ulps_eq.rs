@@ -1,8 +1,21 @@
-use core::cell;
+use core::marker::PhantomData;
+use core::{cell, cmp, num};
+#[cfg(feature = "fixed")]
+use fixed::types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8};
+#[cfg(feature = "fixed")]
+use fixed::{
+    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
+    FixedU8,
+};
+#[cfg(feature = "serde_json")]
+use json;
 #[cfg(feature = "num-complex")]
 use num_complex::Complex;
 use num_traits::Signed;
-
+#[cfg(feature = "ordered-float")]
+use ordered_float::{NotNan, OrderedFloat};
+#[cfg(feature = "serde_json")]
+use serde_json::Value;
 use AbsDiffEq;
 
 /// Equality comparisons between two numbers using both the absolute difference and ULPs
@@ -70,6 +83,103 @@ macro_rules! impl_ulps_eq {
 impl_ulps_eq!(f32, u32);
 impl_ulps_eq!(f64, u64);
 
+// The ULPs between two fixed-point numbers is the difference of their underlying integers, as
+// every step of the integer is one least-significant step of the number
+#[cfg(feature = "fixed")]
+macro_rules! impl_fixed_ulps_eq {
+    ($T:ident, $LeEq:ident) => {
+        impl<Frac: $LeEq> UlpsEq for $T<Frac> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                4
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &$T<Frac>, epsilon: $T<Frac>, max_ulps: u32) -> bool {
+                // For when the numbers are really close together
+                if $T::abs_diff_eq(self, other, epsilon) {
+                    return true;
+                }
+
+                // ULPS difference comparison
+                let ulps = self.to_bits().abs_diff(other.to_bits());
+                u128::from(ulps) <= u128::from(max_ulps)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "fixed")]
+impl_fixed_ulps_eq!(FixedI8, LeEqU8);
+#[cfg(feature = "fixed")]
+impl_fixed_ulps_eq!(FixedI16, LeEqU16);
+#[cfg(feature = "fixed")]
+impl_fixed_ulps_eq!(FixedI32, LeEqU32);
+#[cfg(feature = "fixed")]
+impl_fixed_ulps_eq!(FixedI64, LeEqU64);
+#[cfg(feature = "fixed")]
+impl_fixed_ulps_eq!(FixedI128, LeEqU128);
+#[cfg(feature = "fixed")]
+impl_fixed_ulps_eq!(FixedU8, LeEqU8);
+#[cfg(feature = "fixed")]
+impl_fixed_ulps_eq!(FixedU16, LeEqU16);
+#[cfg(feature = "fixed")]
+impl_fixed_ulps_eq!(FixedU32, LeEqU32);
+#[cfg(feature = "fixed")]
+impl_fixed_ulps_eq!(FixedU64, LeEqU64);
+#[cfg(feature = "fixed")]
+impl_fixed_ulps_eq!(FixedU128, LeEqU128);
+
+#[cfg(feature = "ordered-float")]
+macro_rules! impl_ordered_float_ulps_eq {
+    ($Wrapper:ident, $T:ident) => {
+        impl UlpsEq for $Wrapper<$T> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                $T::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &$Wrapper<$T>, epsilon: $T, max_ulps: u32) -> bool {
+                $T::ulps_eq(&**self, &**other, epsilon, max_ulps)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "ordered-float")]
+impl_ordered_float_ulps_eq!(OrderedFloat, f32);
+#[cfg(feature = "ordered-float")]
+impl_ordered_float_ulps_eq!(OrderedFloat, f64);
+#[cfg(feature = "ordered-float")]
+impl_ordered_float_ulps_eq!(NotNan, f32);
+#[cfg(feature = "ordered-float")]
+impl_ordered_float_ulps_eq!(NotNan, f64);
+
+impl UlpsEq for () {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        0
+    }
+
+    #[inline]
+    fn ulps_eq(&self, _other: &(), _epsilon: (), _max_ulps: u32) -> bool {
+        true
+    }
+}
+
+impl<T: ?Sized> UlpsEq for PhantomData<T> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        0
+    }
+
+    #[inline]
+    fn ulps_eq(&self, _other: &PhantomData<T>, _epsilon: (), _max_ulps: u32) -> bool {
+        true
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 // Derived implementations
 ///////////////////////////////////////////////////////////////////////////////////////////////////
@@ -122,6 +232,31 @@ impl<T: UlpsEq + ?Sized> UlpsEq for cell::RefCell<T> {
     }
 }
 
+macro_rules! impl_wrapper_ulps_eq {
+    ($($Wrapper:ident)::+) => {
+        impl<T: UlpsEq> UlpsEq for $($Wrapper)::+<T> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                T::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(
+                &self,
+                other: &$($Wrapper)::+<T>,
+                epsilon: T::Epsilon,
+                max_ulps: u32,
+            ) -> bool {
+                T::ulps_eq(&self.0, &other.0, epsilon, max_ulps)
+            }
+        }
+    };
+}
+
+impl_wrapper_ulps_eq!(num::Wrapping);
+impl_wrapper_ulps_eq!(num::Saturating);
+impl_wrapper_ulps_eq!(cmp::Reverse);
+
 impl<A, B> UlpsEq<[B]> for [A]
 where
     A: UlpsEq<B>,
@@ -156,3 +291,16 @@ where
             && T::ulps_eq(&self.im, &other.im, epsilon, max_ulps)
     }
 }
+
+#[cfg(feature = "serde_json")]
+impl UlpsEq for Value {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        f64::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Value, epsilon: f64, max_ulps: u32) -> bool {
+        json::ulps_eq(self, other, epsilon, max_ulps).is_ok()
+    }
+}
@@ -0,0 +1,182 @@
+//! Approximate equality of collections whose elements may appear in any order.
+//!
+//! The functions in this module look for a one-to-one matching between the elements of two
+//! collections, such that every pair of matched elements is approximately equal. This is useful
+//! for comparing the outputs of eigenvalue solvers or root finders, which return their results in
+//! an implementation-defined order.
+//!
+//! A maximum matching is found using augmenting paths, so an element that is close to several
+//! elements on the other side will not prevent the others from being matched. This requires
+//! comparing every pair of elements, which takes `O(n·m)` comparisons and space.
+//!
+//! # Example
+//!
+//! ```rust
+//! #[macro_use]
+//! extern crate approx;
+//!
+//! # fn main() {
+//! let roots = [2.0000000001, -1.0, 0.5];
+//! assert_unordered_relative_eq!(roots, [0.5, -1.0, 2.0], max_relative = 1e-9);
+//!
+//! let mismatch = approx::unordered::abs_diff_eq(&[1.0, 2.0], &[2.0, 3.0], 0.1).unwrap_err();
+//! assert_eq!(mismatch.left, vec![(0, &1.0)]);
+//! assert_eq!(mismatch.right, vec![(1, &3.0)]);
+//! # }
+//! ```
+
+use core::fmt;
+use std::vec::Vec;
+
+use {AbsDiff, AbsDiffEq, Comparator, Relative, RelativeEq, Ulps, UlpsEq};
+
+/// The elements of two collections that could not be matched with each other.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mismatch<A, B> {
+    /// The unmatched left-hand elements, along with their indices.
+    pub left: Vec<(usize, A)>,
+    /// The unmatched right-hand elements, along with their indices.
+    pub right: Vec<(usize, B)>,
+}
+
+impl<A: fmt::Debug, B: fmt::Debug> fmt::Display for Mismatch<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "no matching was found for some elements:\n\n    \
+             unmatched left  = {:?}\n    \
+             unmatched right = {:?}",
+            self.left, self.right,
+        )
+    }
+}
+
+/// Try to match the left-hand element `i` with an unvisited right-hand element, reassigning
+/// earlier matches along an augmenting path if needed.
+fn augment(
+    i: usize,
+    adjacent: &[bool],
+    cols: usize,
+    visited: &mut [bool],
+    matched: &mut [Option<usize>],
+) -> bool {
+    for j in 0..cols {
+        if adjacent[i * cols + j] && !visited[j] {
+            visited[j] = true;
+            let free = match matched[j] {
+                None => true,
+                Some(k) => augment(k, adjacent, cols, visited, matched),
+            };
+            if free {
+                matched[j] = Some(i);
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Compare two collections, ignoring order, using the supplied [`Comparator`].
+pub fn compare<I, J, C>(lhs: I, rhs: J, cmp: C) -> Result<(), Mismatch<I::Item, J::Item>>
+where
+    I: IntoIterator,
+    J: IntoIterator,
+    C: Comparator<I::Item, J::Item>,
+{
+    let lhs: Vec<_> = lhs.into_iter().collect();
+    let rhs: Vec<_> = rhs.into_iter().collect();
+    let cols = rhs.len();
+
+    let mut adjacent = Vec::with_capacity(lhs.len() * cols);
+    for x in &lhs {
+        adjacent.extend(rhs.iter().map(|y| cmp.eq(x, y)));
+    }
+
+    // `matched[j]` holds the index of the left-hand element matched with `rhs[j]`
+    let mut matched = vec![None; cols];
+    let mut visited = vec![false; cols];
+    let mut unmatched_left = Vec::new();
+    for i in 0..lhs.len() {
+        for v in visited.iter_mut() {
+            *v = false;
+        }
+        if !augment(i, &adjacent, cols, &mut visited, &mut matched) {
+            unmatched_left.push(i);
+        }
+    }
+
+    if unmatched_left.is_empty() && lhs.len() == rhs.len() {
+        return Ok(());
+    }
+
+    let mut unmatched_left = unmatched_left.into_iter().peekable();
+    let left = lhs
+        .into_iter()
+        .enumerate()
+        .filter(|&(i, _)| unmatched_left.next_if_eq(&i).is_some())
+        .collect();
+    let right = rhs
+        .into_iter()
+        .enumerate()
+        .filter(|&(j, _)| matched[j].is_none())
+        .collect();
+
+    Err(Mismatch { left, right })
+}
+
+/// Compare two collections, ignoring order, using [`AbsDiffEq::abs_diff_eq`].
+#[inline]
+pub fn abs_diff_eq<I, J>(
+    lhs: I,
+    rhs: J,
+    epsilon: <I::Item as AbsDiffEq<J::Item>>::Epsilon,
+) -> Result<(), Mismatch<I::Item, J::Item>>
+where
+    I: IntoIterator,
+    J: IntoIterator,
+    I::Item: AbsDiffEq<J::Item>,
+    <I::Item as AbsDiffEq<J::Item>>::Epsilon: Clone,
+{
+    compare(lhs, rhs, AbsDiff::default().epsilon(epsilon))
+}
+
+/// Compare two collections, ignoring order, using [`RelativeEq::relative_eq`].
+#[inline]
+pub fn relative_eq<I, J>(
+    lhs: I,
+    rhs: J,
+    epsilon: <I::Item as AbsDiffEq<J::Item>>::Epsilon,
+    max_relative: <I::Item as AbsDiffEq<J::Item>>::Epsilon,
+) -> Result<(), Mismatch<I::Item, J::Item>>
+where
+    I: IntoIterator,
+    J: IntoIterator,
+    I::Item: RelativeEq<J::Item>,
+    <I::Item as AbsDiffEq<J::Item>>::Epsilon: Clone,
+{
+    let cmp = Relative::default()
+        .epsilon(epsilon)
+        .max_relative(max_relative);
+    compare(lhs, rhs, cmp)
+}
+
+/// Compare two collections, ignoring order, using [`UlpsEq::ulps_eq`].
+#[inline]
+pub fn ulps_eq<I, J>(
+    lhs: I,
+    rhs: J,
+    epsilon: <I::Item as AbsDiffEq<J::Item>>::Epsilon,
+    max_ulps: u32,
+) -> Result<(), Mismatch<I::Item, J::Item>>
+where
+    I: IntoIterator,
+    J: IntoIterator,
+    I::Item: UlpsEq<J::Item>,
+    <I::Item as AbsDiffEq<J::Item>>::Epsilon: Clone,
+{
+    compare(
+        lhs,
+        rhs,
+        Ulps::default().epsilon(epsilon).max_ulps(max_ulps),
+    )
+}
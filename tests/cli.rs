@@ -0,0 +1,145 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test the `approx-diff` command-line tool
+
+#![cfg(feature = "cli")]
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Tests run in parallel, so each call gets its own pair of files
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+fn write(name: &str, contents: &str) -> PathBuf {
+    let path = env::temp_dir().join(format!("approx-diff-{}-{}", std::process::id(), name));
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+fn approx_diff(args: &[&str], left: &str, right: &str) -> Output {
+    let name = CALLS.fetch_add(1, Ordering::Relaxed);
+    let left = write(&format!("{}-left", name), left);
+    let right = write(&format!("{}-right", name), right);
+    let output = Command::new(env!("CARGO_BIN_EXE_approx-diff"))
+        .args(args)
+        .arg(&left)
+        .arg(&right)
+        .output()
+        .unwrap();
+    fs::remove_file(left).unwrap();
+    fs::remove_file(right).unwrap();
+    output
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn test_equal() {
+    let output = approx_diff(
+        &["--rel", "1e-9"],
+        "1.0 2.0\n3.0 4.0\n",
+        "1.0000000001 2\n3 4\n",
+    );
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "");
+}
+
+#[test]
+fn test_csv() {
+    let left = "name, x, y\na, 1.0, 2.0\nb, 3.0, 4.0\n";
+    let right = "name,x,y\na,1.0,2.0\nb,3.0,4.5\n";
+    let output = approx_diff(&["--abs=0.1"], left, right);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stdout(&output),
+        "3:3: 4.0 != 4.5 (abs 5e-1, rel 1.111111111111111e-1, ulps 562949953421312)\n"
+    );
+}
+
+#[test]
+fn test_text_fields() {
+    let output = approx_diff(&[], "a 1 nan inf\n", "b 1 nan inf\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "1:1: a != b\n");
+}
+
+#[test]
+fn test_missing_fields() {
+    let output = approx_diff(&[], "1 2\n3\n", "1\n3\n4\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stdout(&output),
+        "1:2: 2 is missing on the right\n3:1: 4 is missing on the left\n"
+    );
+}
+
+#[test]
+fn test_tolerances() {
+    let left = "2.0\n1e6\n";
+    let right = "2.0000000000000004\n1000000.001\n";
+
+    let output = approx_diff(&["--ulps", "1"], left, right);
+    assert_eq!(
+        stdout(&output),
+        "2:1: 1e6 != 1000000.001 (abs 1.0000000474974513e-3, rel 1.0000000464974512e-9, ulps 8589935)\n"
+    );
+
+    let output = approx_diff(&["--ulps", "1", "--rel", "1e-8"], left, right);
+    assert_eq!(output.status.code(), Some(0));
+
+    let output = approx_diff(&["--tolerance", "1e-2abs"], left, right);
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[test]
+fn test_combined_tolerances() {
+    let left = "2.0\n1e6\n";
+    let right = "2.0000000000000004\n1000000.001\n";
+
+    let output = approx_diff(&["--abs", "1e-2", "--tolerance", "1ulps"], left, right);
+    assert_eq!(output.status.code(), Some(0));
+
+    let output = approx_diff(&["--tolerance", "1ulps", "--abs", "1e-2"], left, right);
+    assert_eq!(output.status.code(), Some(0));
+
+    let output = approx_diff(&["--abs", "1e-2", "--tolerance", "1e-12abs"], left, right);
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn test_usage_errors() {
+    let output = approx_diff(&["--rel", "x"], "", "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("invalid value for `--rel`: `x`"));
+
+    let output = approx_diff(&["--tolerance", "1e-9"], "", "");
+    assert_eq!(output.status.code(), Some(2));
+
+    let output = approx_diff(&["--unknown=1"], "", "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unknown option `--unknown`"));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_approx-diff"))
+        .arg("missing-file")
+        .arg("other-missing-file")
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+}
@@ -0,0 +1,191 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![no_std]
+
+#[macro_use]
+extern crate approx;
+
+use approx::{AbsDiff, Comparator, Relative, Ulps};
+
+fn check<C: Comparator<f32>>(cmp: C, lhs: f32, rhs: f32) -> bool {
+    cmp.eq(&lhs, &rhs)
+}
+
+struct Within(f64);
+
+impl Comparator<f64> for Within {
+    fn eq(&self, lhs: &f64, rhs: &f64) -> bool {
+        (lhs - rhs).abs() <= self.0
+    }
+}
+
+#[test]
+fn test_builders() {
+    assert!(check(AbsDiff::default(), 1.0, 1.0));
+    assert!(!check(AbsDiff::default(), 1.0, 2.0));
+    assert!(check(AbsDiff::default().epsilon(1.0), 1.0, 2.0));
+
+    assert!(check(Relative::default(), 100000000.0, 100000001.0));
+    assert!(!check(Relative::default(), 10000.0, 10001.0));
+
+    assert!(check(Ulps::default(), 100000000.0, 100000001.0));
+    assert!(!check(Ulps::default(), 10000.0, 10001.0));
+}
+
+#[test]
+fn test_reuse() {
+    let cmp = Relative::default().max_relative(1e-3);
+    assert!(Comparator::eq(&cmp, &10000.0f32, &10001.0));
+    assert!(Comparator::ne(&cmp, &10000.0f32, &10100.0));
+    assert!(cmp.eq(&10000.0f32, &10001.0));
+}
+
+#[test]
+fn test_user_defined() {
+    assert_approx_eq!(1.0, 1.5, using = Within(0.5));
+    assert_approx_ne!(1.0, 1.6, using = Within(0.5));
+    assert!(approx_eq!(1.0, 1.5, using = Within(0.5)));
+    assert!(approx_ne!(1.0, 1.6, using = Within(0.5),));
+}
+
+#[test]
+fn test_closure() {
+    let same_sign = |a: &f64, b: &f64| a.is_sign_negative() == b.is_sign_negative();
+    assert_approx_eq!(1.0, 2.0, using = same_sign);
+    assert_approx_ne!(-1.0, 2.0, using = same_sign,);
+}
+
+#[test]
+fn test_slices() {
+    let cmp = Ulps::default().max_ulps(4);
+    assert_approx_eq!([0.1 + 0.2, 1.0][..], [0.3, 1.0][..], using = cmp);
+}
+
+#[test]
+#[should_panic(expected = "assert_approx_eq!(1.0, 2.0, using = Within(0.5))")]
+fn test_panic_eq() {
+    assert_approx_eq!(1.0, 2.0, using = Within(0.5));
+}
+
+#[test]
+#[should_panic]
+fn test_panic_ne() {
+    assert_approx_ne!(1.0, 1.0, using = AbsDiff::default());
+}
+
+mod test_combinators {
+    use approx::{all_of, any_of, both, by_magnitude, either, AbsDiff, Comparator, Relative, Ulps};
+
+    #[test]
+    fn test_either() {
+        let cmp = either(
+            AbsDiff::default().epsilon(0.5),
+            Relative::default().max_relative(0.1),
+        );
+        assert!(cmp.eq(&1.0, &1.4));
+        assert!(cmp.eq(&100.0, &105.0));
+        assert!(cmp.ne(&1.0, &1.6));
+    }
+
+    #[test]
+    fn test_both() {
+        let cmp = both(
+            AbsDiff::default().epsilon(1.0),
+            Relative::default().max_relative(0.1),
+        );
+        assert!(cmp.eq(&100.0, &100.5));
+        assert!(cmp.ne(&100.0, &105.0));
+        assert!(cmp.ne(&1.0, &1.5));
+    }
+
+    #[test]
+    fn test_any_of() {
+        let cmp = any_of((
+            Ulps::default().max_ulps(4),
+            AbsDiff::default().epsilon(1e-12),
+            Relative::default().max_relative(1e-9),
+        ));
+        assert_approx_eq!(0.1 + 0.2, 0.3, using = cmp);
+        assert_approx_eq!(1e-13, 0.0, using = cmp);
+        assert_approx_eq!(1.0, 1.0 + 1e-10, using = cmp);
+        assert_approx_ne!(1.0, 1.0 + 1e-6, using = cmp);
+
+        let cmp = any_of([
+            AbsDiff::default().epsilon(0.1),
+            AbsDiff::default().epsilon(0.5),
+        ]);
+        assert_approx_eq!(1.0, 1.25, using = cmp);
+        assert_approx_ne!(1.0, 2.0, using = cmp);
+    }
+
+    #[test]
+    fn test_all_of() {
+        let cmp = all_of((AbsDiff::default().epsilon(1.0), Ulps::default().max_ulps(4)));
+        assert_approx_eq!(0.1 + 0.2, 0.3, using = cmp);
+        assert_approx_ne!(0.0, 1.5, using = cmp);
+
+        let cmp = all_of([
+            AbsDiff::default().epsilon(0.1),
+            AbsDiff::default().epsilon(0.5),
+        ]);
+        assert_approx_eq!(1.0, 1.05, using = cmp);
+        assert_approx_ne!(1.0, 1.25, using = cmp);
+    }
+
+    #[test]
+    fn test_by_magnitude() {
+        let cmp = by_magnitude(
+            1.0,
+            AbsDiff::default().epsilon(1e-9),
+            Relative::default().epsilon(0.0).max_relative(1e-6),
+        );
+        assert_approx_eq!(1e-10, 0.0, using = cmp);
+        assert_approx_ne!(1e-8, 0.0, using = cmp);
+        assert_approx_eq!(1000.0001, 1000.0, using = cmp);
+        assert_approx_ne!(1000.01, 1000.0, using = cmp);
+        assert_approx_eq!(-1000.0001, -1000.0, using = cmp);
+    }
+
+    #[test]
+    fn test_slices() {
+        let cmp = either(
+            AbsDiff::default().epsilon(1e-12),
+            Ulps::default().max_ulps(4),
+        );
+        assert_approx_eq!([1e-13, 0.1 + 0.2][..], [0.0, 0.3][..], using = cmp);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "using = Either { first: AbsDiff { epsilon: 1e-12 }, second: Relative { epsilon: 2.220446049250313e-16, max_relative: 1e-9 } }"
+    )]
+    fn test_panic_debug() {
+        let cmp = either(
+            AbsDiff::default().epsilon(1e-12),
+            Relative::default().max_relative(1e-9),
+        );
+        assert_approx_eq!(1.0, 2.0, using = cmp);
+    }
+
+    #[test]
+    #[should_panic(expected = "using = either(AbsDiff::default(), Ulps::default())")]
+    fn test_panic() {
+        assert_approx_eq!(
+            1.0,
+            2.0,
+            using = either(AbsDiff::default(), Ulps::default())
+        );
+    }
+}
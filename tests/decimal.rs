@@ -0,0 +1,115 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test decimal types
+
+#![cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+#![no_std]
+
+#[macro_use]
+extern crate approx;
+
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal {
+    extern crate rust_decimal;
+
+    use self::rust_decimal::Decimal;
+    use approx::{AbsDiffEq, RelativeEq};
+
+    fn dec(num: i64, scale: u32) -> Decimal {
+        Decimal::new(num, scale)
+    }
+
+    #[test]
+    fn test_default_epsilon() {
+        assert_eq!(Decimal::default_epsilon(), dec(1, 28));
+        assert_eq!(Decimal::default_max_relative(), dec(1, 28));
+    }
+
+    #[test]
+    fn test_abs_diff() {
+        assert_abs_diff_eq!(dec(1000, 2), dec(10, 0));
+        assert_abs_diff_ne!(dec(1001, 2), dec(10, 0));
+        assert_abs_diff_eq!(dec(1001, 2), dec(10, 0), epsilon = dec(1, 2));
+        assert_abs_diff_eq!(dec(-1001, 2), dec(-10, 0), epsilon = dec(1, 2));
+        assert_abs_diff_ne!(dec(1002, 2), dec(10, 0), epsilon = dec(1, 2));
+    }
+
+    #[test]
+    fn test_currency_conversion() {
+        // 100 EUR to USD at 1.0837 and back at its reciprocal, rounded to 4 decimal places.
+        let usd = dec(100, 0) * dec(10837, 4);
+        let eur = usd * (Decimal::ONE / dec(10837, 4)).round_dp(4);
+        assert_abs_diff_ne!(eur, dec(100, 0));
+        assert_abs_diff_eq!(eur, dec(100, 0), epsilon = dec(1, 2));
+        assert_relative_eq!(eur, dec(100, 0), max_relative = dec(1, 4));
+    }
+
+    #[test]
+    fn test_relative() {
+        assert_relative_eq!(dec(1000, 0), dec(1001, 0), max_relative = dec(1, 3));
+        assert_relative_ne!(dec(1000, 0), dec(1002, 0), max_relative = dec(1, 3));
+        assert_relative_ne!(dec(-1, 0), dec(1, 0), max_relative = dec(1, 0));
+        assert_relative_eq!(dec(-1, 0), dec(1, 0), max_relative = dec(2, 0));
+    }
+
+    #[test]
+    fn test_overflow() {
+        assert_abs_diff_ne!(Decimal::MAX, Decimal::MIN, epsilon = Decimal::MAX);
+        assert_relative_ne!(Decimal::MAX, Decimal::MIN, max_relative = dec(19, 1));
+        assert_relative_eq!(Decimal::MAX, Decimal::MIN, max_relative = dec(2, 0));
+        assert_relative_eq!(Decimal::MAX, Decimal::MAX, max_relative = Decimal::MAX);
+    }
+}
+
+#[cfg(all(feature = "bigdecimal", feature = "std"))]
+mod bigdecimal {
+    extern crate bigdecimal;
+    extern crate std;
+
+    use self::bigdecimal::BigDecimal;
+    use approx::AbsDiffEq;
+    use core::str::FromStr;
+
+    fn dec(s: &str) -> BigDecimal {
+        BigDecimal::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_default_epsilon() {
+        assert_eq!(BigDecimal::default_epsilon(), dec("1e-100"));
+    }
+
+    #[test]
+    fn test_abs_diff() {
+        assert_abs_diff_eq!(dec("10.00"), dec("10"));
+        assert_abs_diff_ne!(dec("10.01"), dec("10"));
+        assert_abs_diff_eq!(dec("10.01"), dec("10"), epsilon = dec("0.01"));
+        assert_abs_diff_ne!(dec("-10.02"), dec("-10"), epsilon = dec("0.01"));
+    }
+
+    #[test]
+    fn test_relative() {
+        let third = dec("1") / dec("3");
+        assert_relative_ne!(
+            &third * dec("3"),
+            dec("1"),
+            epsilon = dec("0"),
+            max_relative = dec("1e-101")
+        );
+        assert_relative_eq!(&third * dec("3"), dec("1"));
+        assert_relative_eq!(dec("1e40"), dec("1.001e40"), max_relative = dec("0.001"));
+        assert_relative_ne!(dec("1e40"), dec("1.002e40"), max_relative = dec("0.001"));
+    }
+}
@@ -0,0 +1,174 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test fixed-point numbers
+
+#![cfg(feature = "fixed")]
+#![no_std]
+
+#[macro_use]
+extern crate approx;
+extern crate fixed;
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use fixed::types::extra::{U0, U16, U32, U4, U8};
+use fixed::types::{I16F16, I8F8, U16F16};
+use fixed::{FixedI128, FixedI32, FixedU8};
+
+fn i16f16(bits: i32) -> I16F16 {
+    I16F16::from_bits(bits)
+}
+
+mod abs_diff_eq {
+    use super::*;
+
+    #[test]
+    fn test_default_epsilon() {
+        assert_eq!(I16F16::default_epsilon(), I16F16::DELTA);
+        assert_abs_diff_eq!(i16f16(100), i16f16(101));
+        assert_abs_diff_eq!(i16f16(101), i16f16(100));
+        assert_abs_diff_ne!(i16f16(100), i16f16(102));
+    }
+
+    #[test]
+    fn test_epsilon() {
+        let one = I16F16::from_num(1);
+        let two = I16F16::from_num(2);
+        assert_abs_diff_eq!(one, two, epsilon = one);
+        assert_abs_diff_eq!(-one, one, epsilon = two);
+        assert_abs_diff_ne!(-one, one, epsilon = one);
+        assert_abs_diff_ne!(one, one, epsilon = -one);
+    }
+
+    #[test]
+    fn test_extremes() {
+        assert_abs_diff_ne!(I8F8::MIN, I8F8::MAX, epsilon = I8F8::MAX);
+        assert_abs_diff_eq!(
+            FixedU8::<U4>::MIN,
+            FixedU8::<U4>::MAX,
+            epsilon = FixedU8::MAX
+        );
+        assert_abs_diff_eq!(
+            FixedI128::<U0>::MIN,
+            FixedI128::<U0>::MIN + FixedI128::ONE,
+            epsilon = FixedI128::ONE
+        );
+    }
+}
+
+mod relative_eq {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        assert_eq!(I16F16::default_max_relative(), I16F16::ZERO);
+        assert_relative_eq!(i16f16(1 << 20), i16f16((1 << 20) + 1));
+        assert_relative_ne!(i16f16(1 << 20), i16f16((1 << 20) + 32));
+    }
+
+    #[test]
+    fn test_default_no_fraction_bits() {
+        assert!(!relative_eq!(
+            FixedI32::<U0>::from_num(100),
+            FixedI32::<U0>::from_num(1)
+        ));
+        assert_relative_eq!(FixedI32::<U0>::from_num(100), FixedI32::<U0>::from_num(101));
+        assert_relative_ne!(FixedI32::<U0>::from_num(100), FixedI32::<U0>::from_num(102));
+    }
+
+    #[test]
+    fn test_max_relative() {
+        let rel = I16F16::from_num(0.01);
+        assert_relative_eq!(
+            I16F16::from_num(100),
+            I16F16::from_num(100.99),
+            max_relative = rel
+        );
+        assert_relative_eq!(
+            I16F16::from_num(-100.99),
+            I16F16::from_num(-100),
+            max_relative = rel
+        );
+        assert_relative_ne!(
+            I16F16::from_num(100),
+            I16F16::from_num(102),
+            max_relative = rel
+        );
+        assert_relative_ne!(
+            I16F16::from_num(100),
+            I16F16::from_num(100.5),
+            max_relative = -rel
+        );
+    }
+
+    #[test]
+    fn test_overflowing_tolerance() {
+        assert_relative_eq!(
+            U16F16::MAX,
+            U16F16::ZERO,
+            max_relative = U16F16::from_num(2)
+        );
+        assert_relative_eq!(I16F16::MIN, I16F16::MAX, max_relative = I16F16::from_num(2));
+        assert_relative_ne!(
+            I16F16::MIN,
+            I16F16::MAX,
+            max_relative = I16F16::from_num(1.5)
+        );
+    }
+}
+
+mod ulps_eq {
+    use super::*;
+
+    #[test]
+    fn test_ulps() {
+        assert_eq!(I16F16::default_max_ulps(), 4);
+        let x = I16F16::from_num(3);
+        assert_ulps_eq!(x, x + I16F16::DELTA * 4, epsilon = I16F16::ZERO);
+        assert_ulps_eq!(x + I16F16::DELTA * 4, x, epsilon = I16F16::ZERO);
+        assert_ulps_ne!(x, x + I16F16::DELTA * 5, epsilon = I16F16::ZERO);
+        assert_ulps_eq!(
+            x,
+            x + I16F16::DELTA * 5,
+            epsilon = I16F16::ZERO,
+            max_ulps = 5
+        );
+        assert_ulps_eq!(-x, -x - I16F16::DELTA * 2);
+    }
+
+    #[test]
+    fn test_across_zero() {
+        let delta = FixedI32::<U16>::DELTA;
+        assert_ulps_eq!(-delta * 2, delta * 2, epsilon = FixedI32::ZERO);
+        assert_ulps_ne!(-delta * 3, delta * 2, epsilon = FixedI32::ZERO);
+    }
+
+    #[test]
+    fn test_narrow_and_wide() {
+        // The maximum ULPs must not be truncated to the width of the underlying integer
+        assert_ulps_eq!(FixedU8::<U8>::ZERO, FixedU8::<U8>::MAX, max_ulps = 256 + 4);
+        assert_ulps_ne!(FixedU8::<U8>::ZERO, FixedU8::<U8>::MAX, max_ulps = 254);
+        assert_ulps_eq!(
+            FixedI32::<U32>::MIN,
+            FixedI32::<U32>::MAX,
+            max_ulps = u32::MAX
+        );
+        assert_ulps_ne!(
+            FixedI128::<U0>::MIN,
+            FixedI128::<U0>::MAX,
+            max_ulps = u32::MAX
+        );
+        assert!(!FixedI128::<U0>::MIN.ulps_eq(&FixedI128::MAX, FixedI128::ZERO, 0));
+    }
+}
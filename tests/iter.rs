@@ -0,0 +1,119 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![no_std]
+
+#[macro_use]
+extern crate approx;
+
+use approx::iter::{self, Mismatch};
+use approx::{AbsDiff, Ulps};
+
+#[test]
+fn test_equal() {
+    let xs = (0..1000).map(|i| i as f64 * 0.1);
+    let ys = (0..1000).map(|i| i as f64 / 10.0);
+
+    assert_eq!(iter::abs_diff_eq(xs.clone(), ys.clone(), 1e-12), Ok(()));
+    assert_eq!(
+        iter::relative_eq(xs.clone(), ys.clone(), 0.0, 1e-15),
+        Ok(())
+    );
+    assert_eq!(iter::ulps_eq(xs, ys, 0.0, 4), Ok(()));
+}
+
+#[test]
+fn test_empty() {
+    assert_eq!(iter::abs_diff_eq(0..0, 0..0, 0), Ok(()));
+}
+
+#[test]
+fn test_references() {
+    let xs = [1.0f32, 2.0, 3.0];
+    let ys = [1.0f32, 2.0, 3.0000002];
+    assert_eq!(iter::ulps_eq(&xs, &ys, 0.0, 4), Ok(()));
+    assert_iter_ulps_eq!(xs.iter(), ys.iter());
+}
+
+#[test]
+fn test_value_mismatch() {
+    let result = iter::abs_diff_eq([1.0, 2.0, 3.0], [1.0, 2.5, 3.0], 0.1);
+    assert_eq!(
+        result,
+        Err(Mismatch {
+            index: 1,
+            left: Some(2.0),
+            right: Some(2.5),
+        })
+    );
+}
+
+#[test]
+fn test_stops_at_first_mismatch() {
+    let mut consumed = 0;
+    let xs = (0..).inspect(|_| consumed += 1);
+    let result = iter::abs_diff_eq(xs, [0, 1, 5, 3], 0);
+    assert_eq!(result.map_err(|m| m.index), Err(2));
+    assert_eq!(consumed, 3);
+}
+
+#[test]
+fn test_length_mismatch() {
+    let result = iter::abs_diff_eq([1.0, 2.0], [1.0, 2.0, 3.0], 0.0);
+    assert_eq!(
+        result,
+        Err(Mismatch {
+            index: 2,
+            left: None,
+            right: Some(3.0),
+        })
+    );
+
+    let result = iter::relative_eq([1.0, 2.0, 3.0], [1.0], 0.0, 0.0);
+    assert_eq!(
+        result,
+        Err(Mismatch {
+            index: 1,
+            left: Some(2.0),
+            right: None,
+        })
+    );
+}
+
+#[test]
+fn test_compare() {
+    let cmp = approx::either(AbsDiff::default().epsilon(1e-12), Ulps::default());
+    assert_eq!(iter::compare([1e-13, 0.1 + 0.2], [0.0, 0.3], cmp), Ok(()));
+    assert_iter_approx_eq!([1e-13, 0.1 + 0.2], [0.0, 0.3], using = cmp);
+}
+
+#[test]
+fn test_assert() {
+    assert_iter_abs_diff_eq!([1.0, 2.0], [1.0, 2.0]);
+    assert_iter_abs_diff_eq!([1.0, 2.0], [1.5, 2.5], epsilon = 0.5);
+    assert_iter_relative_eq!([1.0, 2.0], [1.0, 2.0], max_relative = 1e-9,);
+    assert_iter_ulps_eq!([1.0, 2.0], [1.0, 2.0], epsilon = 0.0, max_ulps = 4);
+}
+
+#[test]
+#[should_panic(expected = "elements differ at index 1")]
+fn test_assert_panic_value() {
+    assert_iter_relative_eq!([1.0, 2.0], [1.0, 3.0]);
+}
+
+#[test]
+#[should_panic(expected = "right ended at index 2, but left continued with 3.0")]
+fn test_assert_panic_length() {
+    assert_iter_abs_diff_eq!([1.0, 2.0, 3.0], [1.0, 2.0], epsilon = 0.1);
+}
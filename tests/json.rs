@@ -0,0 +1,167 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test approximate comparison of JSON values
+
+#![cfg(feature = "serde_json")]
+
+#[macro_use]
+extern crate approx;
+#[macro_use]
+extern crate serde_json;
+
+use approx::json::Mismatch;
+use approx::{AbsDiff, Relative};
+
+mod abs_diff_eq {
+    #[test]
+    fn test_numbers() {
+        assert_abs_diff_eq!(json!(1.0), json!(1.05), epsilon = 0.1);
+        assert_abs_diff_ne!(json!(1.0), json!(1.5), epsilon = 0.1);
+        assert_abs_diff_eq!(json!(1), json!(1.0));
+        assert_abs_diff_eq!(json!(-3), json!(-3));
+        assert_abs_diff_ne!(json!(3), json!(-3));
+    }
+
+    #[test]
+    fn test_exact_values() {
+        assert_abs_diff_eq!(json!(null), json!(null));
+        assert_abs_diff_eq!(json!("a"), json!("a"));
+        assert_abs_diff_ne!(json!("a"), json!("b"));
+        assert_abs_diff_ne!(json!(true), json!(false));
+        assert_abs_diff_ne!(json!(null), json!(0));
+        assert_abs_diff_ne!(json!("1"), json!(1), epsilon = 1.0);
+    }
+
+    #[test]
+    fn test_containers() {
+        assert_abs_diff_eq!(json!([1.0, [2.0]]), json!([1.05, [2.05]]), epsilon = 0.1);
+        assert_abs_diff_ne!(json!([1.0, 2.0]), json!([1.0]), epsilon = 0.1);
+        assert_abs_diff_eq!(
+            json!({ "a": 1.0, "b": 2.0 }),
+            json!({ "b": 2.05, "a": 1.0 }),
+            epsilon = 0.1
+        );
+        assert_abs_diff_ne!(
+            json!({ "a": 1.0 }),
+            json!({ "a": 1.0, "b": 2.0 }),
+            epsilon = 0.1
+        );
+        assert_abs_diff_ne!(json!({ "a": 1.0 }), json!([1.0]), epsilon = 0.1);
+    }
+}
+
+mod relative_eq {
+    #[test]
+    fn test_numbers() {
+        assert_relative_eq!(json!(0.1 + 0.2), json!(0.3));
+        assert_relative_ne!(json!(1e6), json!(1000001.0));
+        assert_relative_eq!(json!(1e6), json!(1000001.0), max_relative = 1e-5);
+    }
+
+    #[test]
+    fn test_round_tripped() {
+        let expected = json!({ "values": [0.1, 0.2, 0.30000000000000004] });
+        let actual: serde_json::Value =
+            serde_json::from_str(r#"{ "values": [0.1, 0.2, 0.3] }"#).unwrap();
+        assert_relative_eq!(actual, expected);
+    }
+}
+
+mod ulps_eq {
+    #[test]
+    fn test_numbers() {
+        assert_ulps_eq!(json!(2.0), json!(2.0 + 2.0 * f64::EPSILON));
+        assert_ulps_ne!(json!(2.0), json!(2.0 + 2.0 * f64::EPSILON), max_ulps = 0);
+        assert_ulps_eq!(
+            json!([2.0]),
+            json!([2.0 + 2.0 * f64::EPSILON]),
+            max_ulps = 1
+        );
+    }
+}
+
+mod mismatch {
+    use super::*;
+
+    #[test]
+    fn test_pointer() {
+        let left = json!({ "points": [{ "x": 1.0, "y": 2.0 }, { "x": 3.0, "y": 4.0 }] });
+        let right = json!({ "points": [{ "x": 1.0, "y": 2.0 }, { "x": 3.0, "y": 4.5 }] });
+
+        let mismatch = approx::json::abs_diff_eq(&left, &right, 0.1).unwrap_err();
+        assert_eq!(
+            mismatch,
+            Mismatch {
+                pointer: "/points/1/y".to_string(),
+                left: Some(json!(4.0)),
+                right: Some(json!(4.5)),
+            }
+        );
+        assert_eq!(
+            mismatch.to_string(),
+            "values differ at `/points/1/y`:\n\n    left  = 4.0\n    right = 4.5"
+        );
+        assert_eq!(left.pointer(&mismatch.pointer), Some(&json!(4.0)));
+    }
+
+    #[test]
+    fn test_root() {
+        let mismatch = approx::json::abs_diff_eq(&json!(1.0), &json!(2.0), 0.1).unwrap_err();
+        assert_eq!(mismatch.pointer, "");
+        assert_eq!(
+            mismatch.to_string(),
+            "values differ:\n\n    left  = 1.0\n    right = 2.0"
+        );
+    }
+
+    #[test]
+    fn test_missing() {
+        let left = json!({ "a": [1, 2, 3] });
+        let right = json!({ "a": [1, 2] });
+        let mismatch = approx::json::abs_diff_eq(&left, &right, 0.1).unwrap_err();
+        assert_eq!(mismatch.pointer, "/a/2");
+        assert_eq!((mismatch.left, mismatch.right), (Some(json!(3)), None));
+
+        let left = json!({ "a": 1 });
+        let right = json!({ "a": 1, "b": 2 });
+        let mismatch = approx::json::abs_diff_eq(&left, &right, 0.1).unwrap_err();
+        assert_eq!(mismatch.pointer, "/b");
+        assert_eq!(
+            mismatch.to_string(),
+            "values differ at `/b`:\n\n    left is missing\n    right = 2"
+        );
+    }
+
+    #[test]
+    fn test_escaped_keys() {
+        let left = json!({ "a/b": { "c~d": 1.0 } });
+        let right = json!({ "a/b": { "c~d": 2.0 } });
+        let mismatch = approx::json::abs_diff_eq(&left, &right, 0.1).unwrap_err();
+        assert_eq!(mismatch.pointer, "/a~1b/c~0d");
+        assert_eq!(left.pointer(&mismatch.pointer), Some(&json!(1.0)));
+    }
+
+    #[test]
+    fn test_comparators() {
+        let left = json!([1.0, 1e6]);
+        let right = json!([1.0, 1000001.0]);
+        assert!(
+            approx::json::compare(&left, &right, Relative::default().max_relative(1e-5)).is_ok()
+        );
+        assert!(approx::json::compare(&left, &right, AbsDiff::default()).is_err());
+        assert!(approx::json::relative_eq(&left, &right, 0.0, 1e-5).is_ok());
+        assert!(approx::json::ulps_eq(&left, &right, 0.0, 4).is_err());
+    }
+}
@@ -2,8 +2,10 @@ extern crate approx;
 
 mod test_macro_import {
     use approx::{
-        assert_abs_diff_eq, assert_abs_diff_ne, assert_relative_eq, assert_relative_ne,
-        assert_ulps_eq, assert_ulps_ne,
+        assert_abs_diff_eq, assert_abs_diff_ge, assert_abs_diff_le, assert_abs_diff_ne,
+        assert_approx_eq, assert_approx_ne, assert_relative_eq, assert_relative_ge,
+        assert_relative_le, assert_relative_ne, assert_ulps_eq, assert_ulps_ge, assert_ulps_le,
+        assert_ulps_ne,
     };
 
     #[test]
@@ -14,5 +16,30 @@ mod test_macro_import {
         assert_relative_ne!(1.0f32, 2.0f32);
         assert_ulps_eq!(1.0f32, 1.0f32);
         assert_ulps_ne!(1.0f32, 2.0f32);
+        assert_abs_diff_le!(1.0f32, 2.0f32);
+        assert_abs_diff_ge!(2.0f32, 1.0f32);
+        assert_relative_le!(1.0f32, 2.0f32);
+        assert_relative_ge!(2.0f32, 1.0f32);
+        assert_ulps_le!(1.0f32, 2.0f32);
+        assert_ulps_ge!(2.0f32, 1.0f32);
+        assert_approx_eq!(1.0f32, 1.0f32, using = approx::Ulps::default());
+        assert_approx_ne!(1.0f32, 2.0f32, using = approx::Ulps::default());
+    }
+}
+
+mod test_debug_macro_import {
+    use approx::{
+        debug_assert_abs_diff_eq, debug_assert_abs_diff_ne, debug_assert_relative_eq,
+        debug_assert_relative_ne, debug_assert_ulps_eq, debug_assert_ulps_ne,
+    };
+
+    #[test]
+    fn test() {
+        debug_assert_abs_diff_eq!(1.0f32, 1.0f32);
+        debug_assert_abs_diff_ne!(1.0f32, 2.0f32);
+        debug_assert_relative_eq!(1.0f32, 1.0f32);
+        debug_assert_relative_ne!(1.0f32, 2.0f32);
+        debug_assert_ulps_eq!(1.0f32, 1.0f32);
+        debug_assert_ulps_ne!(1.0f32, 2.0f32);
     }
 }
@@ -103,3 +103,85 @@ fn test_rvalue_arguments() {
     assert_relative_eq!(vec![0.0].as_slice(), vec![0.0].as_slice());
     assert_ulps_eq!(vec![0.0].as_slice(), vec![0.0].as_slice());
 }
+
+#[test]
+fn test_debug_assert() {
+    debug_assert_abs_diff_eq!(1.0, 1.0);
+    debug_assert_abs_diff_eq!(1.0, 1.0, epsilon = 1.0,);
+    debug_assert_abs_diff_ne!(1.0, 2.0);
+    debug_assert_abs_diff_ne!(1.0, 2.0, epsilon = 0.5,);
+    debug_assert_relative_eq!(1.0, 1.0);
+    debug_assert_relative_eq!(1.0, 1.0, epsilon = 1.0, max_relative = 1.0,);
+    debug_assert_relative_ne!(1.0, 2.0);
+    debug_assert_relative_ne!(1.0, 2.0, epsilon = 0.5, max_relative = 0.1,);
+    debug_assert_ulps_eq!(1.0, 1.0);
+    debug_assert_ulps_eq!(1.0, 1.0, epsilon = 1.0, max_ulps = 1,);
+    debug_assert_ulps_ne!(1.0, 2.0);
+    debug_assert_ulps_ne!(1.0, 2.0, epsilon = 0.5, max_ulps = 1,);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "assert_relative_eq!(1.0, 2.0)")]
+fn test_debug_assert_panic() {
+    debug_assert_relative_eq!(1.0, 2.0);
+}
+
+#[test]
+#[cfg(not(debug_assertions))]
+fn test_debug_assert_release() {
+    debug_assert_relative_eq!(1.0, 2.0);
+}
+
+#[test]
+fn test_ordering() {
+    let _: bool = abs_diff_le!(1.0, 1.0);
+    let _: bool = abs_diff_ge!(1.0, 1.0, epsilon = 1.0,);
+    let _: bool = relative_le!(1.0, 1.0, epsilon = 1.0, max_relative = 1.0);
+    let _: bool = relative_ge!(1.0, 1.0,);
+    let _: bool = ulps_le!(1.0, 1.0, max_ulps = 1);
+    let _: bool = ulps_ge!(1.0, 1.0, epsilon = 1.0, max_ulps = 1,);
+}
+
+#[test]
+fn test_in_range() {
+    let _: bool = abs_diff_in_range!(1.0, 0.0..=1.0);
+    let _: bool = abs_diff_in_range!(1.0, 0.0..=1.0, epsilon = 1.0,);
+    let _: bool = relative_in_range!(1.0, 0.0..=1.0, epsilon = 1.0, max_relative = 1.0);
+    let _: bool = relative_in_range!(1.0, 0.0..=1.0,);
+    let _: bool = ulps_in_range!(1.0, 0.0..=1.0, max_ulps = 1);
+    let _: bool = ulps_in_range!(1.0, 0.0..=1.0, epsilon = 1.0, max_ulps = 1,);
+}
+
+#[test]
+fn test_approx_eq() {
+    let _: bool = approx_eq!(1.0, 1.0, using = approx::AbsDiff::default());
+    let _: bool = approx_eq!(1.0, 1.0, using = approx::Ulps::default(),);
+    let _: bool = approx_ne!(1.0, 1.0, using = approx::Relative::default());
+    let _: bool = approx_ne!(1.0, 1.0, using = approx::Relative::default(),);
+}
+
+#[test]
+fn test_angle() {
+    let _: bool = angle_eq!(1.0, 1.0);
+    let _: bool = angle_eq!(1.0, 1.0, epsilon = 1.0, period = 360.0,);
+    let _: bool = angle_ne!(1.0, 1.0, period = 360.0);
+    let _: bool = angle_ne!(1.0, 1.0,);
+}
+
+#[test]
+fn test_quaternion() {
+    let _: bool = quaternion_eq!([0.0, 0.0, 0.0, 1.0], [0.0, 0.0, 0.0, -1.0]);
+    let _: bool = quaternion_eq!([0.0, 0.0, 0.0, 1.0], [0.0, 0.0, 0.0, -1.0], epsilon = 1.0,);
+    let _: bool = quaternion_ne!([0.0, 0.0, 0.0, 1.0], [0.0, 0.0, 0.0, -1.0], epsilon = 1.0);
+    let _: bool = quaternion_ne!([0.0, 0.0, 0.0, 1.0], [0.0, 0.0, 0.0, -1.0],);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_rotation() {
+    let _: bool = rotation_eq!([0.0, 0.0, 0.0, 1.0], [0.0, 0.0, 0.0, -1.0]);
+    let _: bool = rotation_eq!([0.0, 0.0, 0.0, 1.0], [0.0, 0.0, 0.0, -1.0], max_angle = 1.0,);
+    let _: bool = rotation_ne!([0.0, 0.0, 0.0, 1.0], [0.0, 0.0, 0.0, -1.0], max_angle = 1.0);
+    let _: bool = rotation_ne!([0.0, 0.0, 0.0, 1.0], [0.0, 0.0, 0.0, -1.0],);
+}
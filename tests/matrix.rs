@@ -0,0 +1,150 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "std")]
+
+#[macro_use]
+extern crate approx;
+
+use approx::matrix::{Location, Matrix, MatrixDiff, MatrixNorm, Mismatch};
+
+const A: [f64; 6] = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+
+#[test]
+fn test_equal() {
+    let b = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0 + 1e-12];
+    let a = Matrix::new(&A, 2, 3);
+    let b = Matrix::new(&b, 2, 3);
+
+    assert_matrix_eq!(a, b, max_relative = 1e-9);
+    assert_matrix_eq!(a, b, norm = MatrixNorm::One, max_relative = 1e-9);
+    assert_matrix_eq!(a, b, norm = MatrixNorm::Inf, max_relative = 1e-9);
+    assert_matrix_ne!(a, b, epsilon = 0.0, max_relative = 0.0);
+}
+
+#[test]
+fn test_stride() {
+    let padded = [1.0, 2.0, 3.0, -1.0, 4.0, 5.0, 6.0];
+    let a = Matrix::new(&A, 2, 3);
+    let b = Matrix::with_stride(&padded, 2, 3, 4);
+
+    assert_eq!(*b.get(1, 0), 4.0);
+    assert_matrix_eq!(a, b, epsilon = 0.0, max_relative = 0.0);
+}
+
+#[test]
+fn test_norms() {
+    let zeros = [0.0; 6];
+    let a = Matrix::new(&A, 2, 3);
+    let zeros = Matrix::new(&zeros, 2, 3);
+
+    // ‖A‖_F = sqrt(91), ‖A‖_1 = 9, ‖A‖_∞ = 15
+    let check = |norm, expected: f64, worst| {
+        let result = MatrixDiff::default()
+            .norm(norm)
+            .epsilon(0.0)
+            .compare(&a, &zeros);
+        assert_eq!(
+            result,
+            Err(Mismatch::Norm {
+                norm,
+                diff: expected,
+                tolerance: 0.0,
+                worst,
+            })
+        );
+    };
+    check(
+        MatrixNorm::Frobenius,
+        91.0f64.sqrt(),
+        Location::Element(1, 2),
+    );
+    check(MatrixNorm::One, 9.0, Location::Column(2));
+    check(MatrixNorm::Inf, 15.0, Location::Row(1));
+}
+
+#[test]
+fn test_relative_tolerance() {
+    // A perturbation that is large relative to its element, but small relative to the matrix.
+    let a = [1e6, 1e-6, 1e-6, 1e6];
+    let b = [1e6, 2e-6, 1e-6, 1e6];
+    let a = Matrix::new(&a, 2, 2);
+    let b = Matrix::new(&b, 2, 2);
+
+    assert_matrix_eq!(a, b, epsilon = 0.0, max_relative = 1e-9);
+    assert_matrix_eq!(
+        a,
+        b,
+        norm = MatrixNorm::One,
+        epsilon = 0.0,
+        max_relative = 1e-9
+    );
+}
+
+#[test]
+fn test_shape_mismatch() {
+    let a = Matrix::new(&A, 2, 3);
+    let b = Matrix::new(&A, 3, 2);
+    assert_eq!(
+        MatrixDiff::default().compare(&a, &b),
+        Err(Mismatch::Shape {
+            left: (2, 3),
+            right: (3, 2),
+        })
+    );
+    assert!(matrix_ne!(a, b));
+}
+
+#[test]
+fn test_nan() {
+    let b = [1.0, 2.0, f64::NAN, 4.0, 5.0, 6.0];
+    let a = Matrix::new(&A, 2, 3);
+    let b = Matrix::new(&b, 2, 3);
+    assert!(matrix_ne!(a, b));
+}
+
+#[test]
+#[should_panic(expected = "(largest difference in row 1)")]
+fn test_panic_row() {
+    let b = [1.0, 2.0, 3.0, 4.0, 5.5, 6.0];
+    assert_matrix_eq!(
+        Matrix::new(&A, 2, 3),
+        Matrix::new(&b, 2, 3),
+        norm = MatrixNorm::Inf
+    );
+}
+
+#[test]
+#[should_panic(expected = "(largest difference in column 0)")]
+fn test_panic_column() {
+    let b = [1.5, 2.0, 3.0, 4.0, 5.0, 6.0];
+    assert_matrix_eq!(
+        Matrix::new(&A, 2, 3),
+        Matrix::new(&b, 2, 3),
+        norm = MatrixNorm::One
+    );
+}
+
+#[test]
+#[should_panic(expected = "(largest difference in element (0, 1))")]
+fn test_panic_element() {
+    let b = [1.0, 2.5, 3.0, 4.0, 5.1, 6.0];
+    assert_matrix_eq!(Matrix::new(&A, 2, 3), Matrix::new(&b, 2, 3));
+}
+
+#[test]
+#[should_panic(expected = "needs 6 elements, but only 5 were supplied")]
+fn test_too_short() {
+    Matrix::new(&A[..5], 2, 3);
+}
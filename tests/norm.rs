@@ -0,0 +1,132 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "std")]
+
+#[macro_use]
+extern crate approx;
+
+mod test_norm {
+    use approx::Norm;
+    use std::f64;
+
+    #[test]
+    fn test_l1() {
+        assert_eq!(Norm::L1.of(vec![3.0, -4.0]), 7.0);
+        assert_eq!(Norm::L1.of(Vec::<f64>::new()), 0.0);
+    }
+
+    #[test]
+    fn test_l2() {
+        assert_eq!(Norm::L2.of(vec![3.0, -4.0]), 5.0);
+        assert_eq!(Norm::L2.of(vec![0.0f32, 0.0]), 0.0);
+        assert_eq!(Norm::L2.of(vec![1e300, 1e300]), 1e300 * 2.0f64.sqrt());
+        assert_eq!(Norm::L2.of(vec![3e-300, 4e-300]), 5e-300);
+        assert_eq!(
+            Norm::L2.of(vec![f64::INFINITY, 1.0, f64::INFINITY]),
+            f64::INFINITY
+        );
+        assert!(Norm::L2.of(vec![f64::INFINITY, f64::NAN]).is_nan());
+    }
+
+    #[test]
+    fn test_inf() {
+        assert_eq!(Norm::Inf.of(vec![3.0, -4.0, 1.0]), 4.0);
+        assert!(Norm::Inf.of(vec![1.0, f64::NAN, 2.0]).is_nan());
+    }
+}
+
+mod test_norm_diff {
+    use approx::{Norm, NormDiff};
+    use std::f64;
+
+    #[test]
+    fn test_tiny_components() {
+        let a = [1.0, 1e-20];
+        let b = [1.0, 2e-20];
+
+        assert_relative_ne!(a[..], b[..], epsilon = 0.0);
+        assert_norm_eq!(a, b);
+        assert_norm_eq!(a, b, norm = Norm::L1);
+        assert_norm_eq!(a, b, norm = Norm::Inf);
+    }
+
+    #[test]
+    fn test_tolerances() {
+        let a = [1.0, 2.0, 3.0];
+        let b = [1.1, 2.0, 3.0];
+
+        assert_norm_ne!(a, b);
+        assert_norm_eq!(a, b, epsilon = 0.1 + 1e-12);
+        assert_norm_eq!(a, b, epsilon = 0.0, max_relative = 0.1 / 14.0f64.sqrt());
+        assert_norm_ne!(
+            a,
+            b,
+            norm = Norm::L1,
+            epsilon = 0.0,
+            max_relative = 0.09 / 6.1
+        );
+        assert_norm_eq!(a, b, norm = Norm::Inf, epsilon = 0.0, max_relative = 0.034);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let a = [f64::MAX / 2.0, f64::MAX / 2.0];
+        let b = [f64::MAX / 2.0, f64::MAX / 2.0 * (1.0 + 1e-15)];
+        assert_norm_eq!(a, b, max_relative = 1e-14);
+    }
+
+    #[test]
+    fn test_slices() {
+        let a = vec![1.0f32, 2.0];
+        let b = vec![1.0f32, 2.0000002];
+        assert_norm_eq!(a, b);
+        assert_norm_eq!(a[..], b[..]);
+        assert!(NormDiff::default().eq(&a, &b));
+    }
+
+    #[test]
+    fn test_length_mismatch() {
+        assert_norm_ne!(vec![1.0, 2.0], vec![1.0, 2.0, 0.0]);
+    }
+
+    #[test]
+    fn test_nan() {
+        assert_norm_ne!([1.0, f64::NAN], [1.0, f64::NAN]);
+    }
+
+    #[test]
+    fn test_infinite() {
+        let a = [1.0, f64::INFINITY, f64::NEG_INFINITY];
+        assert_norm_eq!(a, a);
+        assert_norm_eq!(a, a, max_relative = 0.0);
+        assert_norm_eq!(a, a, norm = Norm::Inf);
+        assert_norm_eq!(a, [1.0 + 1e-15, f64::INFINITY, f64::NEG_INFINITY]);
+        assert_norm_ne!(a, [1.0, f64::INFINITY, f64::INFINITY]);
+        assert_norm_ne!(a, [1.0, 2.0, f64::NEG_INFINITY]);
+    }
+
+    #[test]
+    fn test_comparator() {
+        let cmp = NormDiff::default().norm(Norm::Inf).epsilon(1e-9);
+        assert_approx_eq!([1.0, 2.0], [1.0, 2.0 + 1e-10], using = cmp);
+        assert_approx_eq!([1.0, 2.0][..], [1.0, 2.0 + 1e-10][..], using = cmp);
+    }
+
+    #[test]
+    #[should_panic(expected = "assert_norm_eq!([1.0, 2.0], [1.0, 3.0], norm = Norm::L1)")]
+    fn test_panic() {
+        assert_norm_eq!([1.0, 2.0], [1.0, 3.0], norm = Norm::L1);
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test the ordered-float wrappers
+
+#![cfg(feature = "ordered-float")]
+#![no_std]
+
+#[macro_use]
+extern crate approx;
+extern crate ordered_float;
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use ordered_float::{NotNan, OrderedFloat};
+
+mod ordered {
+    use super::*;
+
+    #[test]
+    fn test_abs_diff() {
+        assert_abs_diff_eq!(OrderedFloat(1.0f64), OrderedFloat(1.0f64));
+        assert_abs_diff_eq!(OrderedFloat(1.0f32), OrderedFloat(1.5f32), epsilon = 0.5);
+        assert_abs_diff_ne!(OrderedFloat(1.0f64), OrderedFloat(1.5f64), epsilon = 0.4);
+        assert_eq!(OrderedFloat::<f64>::default_epsilon(), f64::EPSILON);
+    }
+
+    #[test]
+    fn test_relative() {
+        assert_relative_eq!(
+            OrderedFloat(1e10f64),
+            OrderedFloat(1e10 + 1.0),
+            max_relative = 1e-9
+        );
+        assert_relative_ne!(
+            OrderedFloat(1e10f64),
+            OrderedFloat(1e10 + 1e3),
+            max_relative = 1e-9
+        );
+        assert_eq!(OrderedFloat::<f32>::default_max_relative(), f32::EPSILON);
+    }
+
+    #[test]
+    fn test_ulps() {
+        assert_ulps_eq!(OrderedFloat(2.0f32), OrderedFloat(2.0 + 2.0 * f32::EPSILON));
+        assert_ulps_ne!(
+            OrderedFloat(2.0f64),
+            OrderedFloat(2.0 + 2.0 * f64::EPSILON),
+            max_ulps = 0
+        );
+        assert_eq!(OrderedFloat::<f64>::default_max_ulps(), 4);
+    }
+
+    #[test]
+    fn test_nan() {
+        // `OrderedFloat` considers NaN equal to itself, but approximate comparisons follow the
+        // wrapped float.
+        assert_eq!(OrderedFloat(f64::NAN), OrderedFloat(f64::NAN));
+        assert_abs_diff_ne!(OrderedFloat(f64::NAN), OrderedFloat(f64::NAN));
+        assert_relative_ne!(OrderedFloat(f32::NAN), OrderedFloat(f32::NAN));
+        assert_ulps_ne!(OrderedFloat(f32::NAN), OrderedFloat(f32::NAN));
+    }
+
+    #[test]
+    fn test_slices() {
+        let sorted = [
+            OrderedFloat(0.1f64),
+            OrderedFloat(0.2),
+            OrderedFloat(0.30000000000000004),
+        ];
+        let expected = [OrderedFloat(0.1f64), OrderedFloat(0.2), OrderedFloat(0.3)];
+        assert_relative_eq!(sorted[..], expected[..]);
+    }
+}
+
+mod not_nan {
+    use super::*;
+
+    fn not_nan(x: f64) -> NotNan<f64> {
+        NotNan::new(x).unwrap()
+    }
+
+    #[test]
+    fn test_abs_diff() {
+        assert_abs_diff_eq!(not_nan(1.0), not_nan(1.0));
+        assert_abs_diff_eq!(not_nan(1.0), not_nan(1.5), epsilon = 0.5);
+        assert_abs_diff_ne!(not_nan(1.0), not_nan(1.5), epsilon = 0.4);
+        assert_eq!(NotNan::<f32>::default_epsilon(), f32::EPSILON);
+    }
+
+    #[test]
+    fn test_relative() {
+        assert_relative_eq!(not_nan(1e10), not_nan(1e10 + 1.0), max_relative = 1e-9);
+        assert_relative_ne!(not_nan(1e10), not_nan(1e10 + 1e3), max_relative = 1e-9);
+        assert!(NotNan::new(1.0f32)
+            .unwrap()
+            .relative_eq(&NotNan::new(1.0).unwrap(), 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_ulps() {
+        assert_ulps_eq!(not_nan(2.0), not_nan(2.0 + 2.0 * f64::EPSILON));
+        assert_ulps_ne!(
+            not_nan(2.0),
+            not_nan(2.0 + 2.0 * f64::EPSILON),
+            max_ulps = 0
+        );
+        assert!(NotNan::new(1.0f32)
+            .unwrap()
+            .ulps_eq(&NotNan::new(1.0).unwrap(), 0.0, 0));
+    }
+
+    #[test]
+    fn test_infinities() {
+        assert_relative_eq!(not_nan(f64::INFINITY), not_nan(f64::INFINITY));
+        assert_relative_ne!(not_nan(f64::INFINITY), not_nan(f64::NEG_INFINITY));
+        assert_ulps_ne!(not_nan(f64::INFINITY), not_nan(1.0));
+    }
+}
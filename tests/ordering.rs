@@ -0,0 +1,112 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![no_std]
+
+#[macro_use]
+extern crate approx;
+
+mod test_abs_diff {
+    use approx::AbsDiff;
+    use core::cmp::Ordering;
+    use core::f64;
+
+    #[test]
+    fn test_le_ge() {
+        assert_abs_diff_le!(1.0, 2.0);
+        assert_abs_diff_le!(1.0 + 1e-9, 1.0, epsilon = 1e-6);
+        assert_abs_diff_ge!(2.0, 1.0);
+        assert_abs_diff_ge!(1.0 - 1e-9, 1.0, epsilon = 1e-6);
+
+        assert!(!abs_diff_le!(1.0 + 1e-3, 1.0, epsilon = 1e-6));
+        assert!(!abs_diff_ge!(1.0 - 1e-3, 1.0, epsilon = 1e-6));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_le_panic() {
+        assert_abs_diff_le!(2.0, 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ge_panic() {
+        assert_abs_diff_ge!(1.0, 2.0);
+    }
+
+    #[test]
+    fn test_approx_cmp() {
+        let cmp = || AbsDiff::default().epsilon(0.5);
+        assert_eq!(cmp().approx_cmp(&1.0, &1.4), Some(Ordering::Equal));
+        assert_eq!(cmp().approx_cmp(&1.0, &2.0), Some(Ordering::Less));
+        assert_eq!(cmp().approx_cmp(&2.0, &1.0), Some(Ordering::Greater));
+        assert_eq!(cmp().approx_cmp(&f64::NAN, &1.0), None);
+    }
+}
+
+mod test_relative {
+    use approx::Relative;
+    use core::cmp::Ordering;
+    use core::f32;
+
+    #[test]
+    fn test_le_ge() {
+        assert_relative_le!(100000001.0f32, 100000000.0f32);
+        assert_relative_ge!(100000000.0f32, 100000001.0f32);
+        assert_relative_le!(1.0f32, f32::INFINITY);
+        assert_relative_ge!(f32::INFINITY, f32::INFINITY);
+
+        assert!(!relative_le!(10001.0f32, 10000.0f32));
+        assert!(!relative_ge!(10000.0f32, 10001.0f32));
+        assert!(!relative_le!(f32::NAN, 1.0f32));
+    }
+
+    #[test]
+    fn test_approx_cmp() {
+        let cmp = || Relative::default().max_relative(0.1);
+        assert_eq!(cmp().approx_cmp(&100.0, &105.0), Some(Ordering::Equal));
+        assert_eq!(cmp().approx_cmp(&100.0, &120.0), Some(Ordering::Less));
+        assert_eq!(cmp().approx_cmp(&120.0, &100.0), Some(Ordering::Greater));
+    }
+}
+
+mod test_ulps {
+    use approx::Ulps;
+    use core::cmp::Ordering;
+    use core::f32;
+
+    #[test]
+    fn test_le_ge() {
+        let x = 1.0f32;
+        let next = f32::from_bits(x.to_bits() + 1);
+        let far = f32::from_bits(x.to_bits() + 10);
+
+        assert_ulps_le!(next, x, epsilon = 0.0);
+        assert_ulps_ge!(x, next, epsilon = 0.0);
+        assert!(!ulps_le!(far, x, epsilon = 0.0, max_ulps = 4));
+        assert!(!ulps_ge!(x, far, epsilon = 0.0, max_ulps = 4));
+    }
+
+    #[test]
+    fn test_approx_cmp() {
+        let x = 1.0f32;
+        let far = f32::from_bits(x.to_bits() + 10);
+        let cmp = Ulps::default().epsilon(0.0).max_ulps(10);
+        assert_eq!(cmp.approx_cmp(&x, &far), Some(Ordering::Equal));
+        let cmp = Ulps::default().epsilon(0.0).max_ulps(4);
+        assert_eq!(cmp.approx_cmp(&x, &far), Some(Ordering::Less));
+        let cmp = Ulps::default().epsilon(0.0).max_ulps(4);
+        assert_eq!(cmp.approx_cmp(&far, &x), Some(Ordering::Greater));
+    }
+}
@@ -0,0 +1,181 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![no_std]
+
+#[macro_use]
+extern crate approx;
+
+mod test_f32 {
+    use core::f32;
+    use core::f32::consts::PI;
+
+    #[test]
+    fn test_basic() {
+        assert_angle_eq!(1.0f32, 1.0f32);
+        assert_angle_ne!(1.0f32, 2.0f32);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_basic_panic_eq() {
+        assert_angle_eq!(1.0f32, 2.0f32);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_basic_panic_ne() {
+        assert_angle_ne!(1.0f32, 1.0f32);
+    }
+
+    #[test]
+    fn test_wrap() {
+        assert_angle_eq!(0.0f32, 2.0 * PI);
+        assert_angle_eq!(PI, -PI);
+        assert_angle_eq!(0.0f32, 2.0 * PI - 1e-6, epsilon = 1e-5);
+        assert_angle_eq!(2.0 * PI - 1e-6, 1e-6, epsilon = 1e-5);
+        assert_angle_ne!(0.0f32, PI, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_degrees() {
+        assert_angle_eq!(359.5f32, -0.5f32, period = 360.0);
+        assert_angle_eq!(720.0f32, 0.0f32, period = 360.0);
+        assert_angle_eq!(1.0f32, 358.0f32, period = 360.0, epsilon = 3.0);
+        assert_angle_ne!(1.0f32, 358.0f32, period = 360.0, epsilon = 2.5);
+    }
+
+    #[test]
+    fn test_nan() {
+        assert_angle_ne!(f32::NAN, f32::NAN);
+        assert_angle_ne!(f32::NAN, 0.0);
+        assert_angle_ne!(0.0, f32::INFINITY);
+    }
+}
+
+#[cfg(test)]
+mod test_f64 {
+    use core::f64;
+    use core::f64::consts::PI;
+
+    #[test]
+    fn test_nan() {
+        assert_angle_ne!(f64::NAN, f64::NAN);
+        assert_angle_ne!(f64::INFINITY, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_basic() {
+        assert_angle_eq!(1.0f64, 1.0f64);
+        assert_angle_ne!(1.0f64, 2.0f64);
+    }
+
+    #[test]
+    fn test_wrap() {
+        assert_angle_eq!(0.0, 2.0 * PI - 1e-12);
+        assert!(angle_eq!(0.0, 2.0 * PI - 1e-12));
+        assert_angle_ne!(0.0, 2.0 * PI - 1e-6);
+        assert_abs_diff_ne!(0.0, 2.0 * PI - 1e-12, epsilon = 1e-9);
+        assert_angle_eq!(0.0, 2.0 * PI - 1e-12, epsilon = 1e-9);
+        assert_angle_eq!(-3.0 * PI, PI, epsilon = 1e-9);
+        assert_angle_eq!(0.25, 0.25 + 10.0, period = 1.0, epsilon = 1e-9);
+        assert_angle_eq!(0.25, 0.25 - 10.0, period = -1.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_degrees() {
+        assert_angle_eq!(359.9999999, 0.0, period = 360.0, epsilon = 1e-6);
+        assert_angle_ne!(180.0, 0.0, period = 360.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_builder() {
+        use approx::{AbsDiff, Periodic};
+
+        assert!(AbsDiff::default()
+            .epsilon(1e-9)
+            .period(360.0)
+            .eq(&359.5, &-0.5));
+        assert!(Periodic::default().epsilon(1e-9).eq(&PI, &-PI));
+        assert!(Periodic::default().period(360.0).ne(&90.0, &-90.0));
+        assert!(AbsDiff::default()
+            .period(2.0 * PI)
+            .eq(&0.0, &(2.0 * PI - 1e-12)));
+        assert!(AbsDiff::default()
+            .epsilon(1e-15)
+            .period(2.0 * PI)
+            .ne(&0.0, &(2.0 * PI - 1e-12)));
+    }
+
+    #[test]
+    fn test_zero_period() {
+        assert_angle_eq!(1.0, 1.0 + 1e-12, period = 0.0, epsilon = 1e-9);
+        assert_angle_ne!(0.0, 2.0 * PI, period = 0.0);
+        assert_angle_ne!(0.0, 1.0, period = 0.0);
+    }
+}
+
+mod test_ref {
+    mod test_f32 {
+        #[test]
+        fn test_basic() {
+            assert_angle_eq!(&1.0f32, &1.0f32);
+            assert_angle_ne!(&1.0f32, &2.0f32);
+        }
+    }
+
+    mod test_f64 {
+        #[test]
+        fn test_basic() {
+            assert_angle_eq!(&1.0f64, &1.0f64);
+            assert_angle_ne!(&1.0f64, &2.0f64);
+        }
+    }
+}
+
+mod test_slice {
+    mod test_f32 {
+        use core::f32::consts::PI;
+
+        #[test]
+        fn test_basic() {
+            assert_angle_eq!([1.0f32, 2.0f32][..], [1.0f32, 2.0f32][..]);
+            assert_angle_ne!([1.0f32, 2.0f32][..], [2.0f32, 1.0f32][..]);
+            assert_angle_eq!([0.0f32, PI][..], [2.0 * PI, -PI][..]);
+        }
+    }
+
+    mod test_f64 {
+        use core::f64::consts::PI;
+
+        #[test]
+        fn test_basic() {
+            assert_angle_eq!([1.0f64, 2.0f64][..], [1.0f64, 2.0f64][..]);
+            assert_angle_ne!([1.0f64, 2.0f64][..], [2.0f64, 1.0f64][..]);
+            assert_angle_eq!([0.0, PI][..], [2.0 * PI, -PI][..], epsilon = 1e-9);
+            assert_angle_eq!([0.0, 90.0][..], [360.0, -270.0][..], period = 360.0);
+        }
+    }
+}
+
+mod test_comparator {
+    use approx::AbsDiff;
+
+    #[test]
+    fn test_using() {
+        let cmp = AbsDiff::default().epsilon(1e-9).period(360.0);
+        assert_approx_eq!(359.5, -0.5, using = cmp);
+        assert_iter_approx_eq!([0.0, 90.0], [360.0, -270.0], using = cmp);
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "std")]
+
+#[macro_use]
+extern crate approx;
+#[cfg(feature = "num-complex")]
+extern crate num_complex;
+
+mod test_sign {
+    use approx::{up_to_sign, AbsDiff, Comparator, Relative, Ulps};
+
+    #[test]
+    fn test_sign() {
+        let v = [0.6, -0.8, 0.0];
+        let w = [-0.6, 0.8, -0.0];
+
+        assert_approx_eq!(v, v, using = up_to_sign(Relative::default()));
+        assert_approx_eq!(v, w, using = up_to_sign(Relative::default()));
+        assert_approx_eq!(v[..], w[..], using = up_to_sign(Ulps::default()));
+        assert_approx_eq!(
+            v,
+            [-0.6 + 1e-12, 0.8, 0.0],
+            using = up_to_sign(AbsDiff::default().epsilon(1e-9))
+        );
+    }
+
+    #[test]
+    fn test_mixed_sign() {
+        let cmp = up_to_sign(Relative::default());
+        assert!(cmp.ne(&[0.6, -0.8], &[-0.6, -0.8]));
+        assert!(cmp.ne(&[0.6, -0.8][..], &[0.6, -0.8, 0.0][..]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_panic() {
+        assert_approx_eq!(
+            [1.0, 2.0],
+            [1.0, -2.0],
+            using = up_to_sign(Relative::default())
+        );
+    }
+}
+
+#[cfg(feature = "num-complex")]
+mod test_phase {
+    use approx::{up_to_phase, up_to_sign, Comparator, Relative};
+    use num_complex::Complex;
+
+    fn rotate(v: &[Complex<f64>], theta: f64) -> Vec<Complex<f64>> {
+        let phase = Complex::from_polar(1.0, theta);
+        v.iter().map(|x| x * phase).collect()
+    }
+
+    #[test]
+    fn test_phase() {
+        let v = [
+            Complex::new(0.5, 0.5),
+            Complex::new(0.0, -0.5),
+            Complex::new(0.5, 0.0),
+        ];
+        let cmp = up_to_phase(Relative::default().epsilon(1e-12));
+
+        for &theta in &[0.0, 0.1, 1.0, 3.0, -2.5, std::f64::consts::PI] {
+            let w = rotate(&v, theta);
+            assert_approx_eq!(v[..], w[..], using = cmp);
+            assert_approx_eq!(w[..], v[..], using = cmp);
+        }
+    }
+
+    #[test]
+    fn test_arrays() {
+        let v = [Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        let w = [Complex::new(0.0, 1.0), Complex::new(-1.0, 0.0)];
+        assert_approx_eq!(v, w, using = up_to_phase(Relative::default()));
+    }
+
+    #[test]
+    fn test_sign_of_complex() {
+        let v = [Complex::new(1.0, 2.0), Complex::new(-3.0, 0.5)];
+        let w = [Complex::new(-1.0, -2.0), Complex::new(3.0, -0.5)];
+        assert_approx_eq!(v, w, using = up_to_sign(Relative::default()));
+    }
+
+    #[test]
+    fn test_zero() {
+        let zero = [Complex::new(0.0, 0.0); 2];
+        let cmp = up_to_phase(Relative::default());
+        assert!(cmp.eq(&zero, &zero));
+        assert!(cmp.ne(&zero, &[Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)]));
+    }
+
+    #[test]
+    fn test_not_a_global_phase() {
+        let v = [Complex::new(1.0, 0.0), Complex::new(1.0, 0.0)];
+        let w = [Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)];
+        assert!(up_to_phase(Relative::default().epsilon(1e-9)).ne(&v, &w));
+    }
+}
@@ -0,0 +1,176 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test quaternion comparisons
+
+#![no_std]
+
+#[macro_use]
+extern crate approx;
+
+use approx::quaternion::double_cover;
+use approx::{Comparator, Relative, Ulps};
+
+mod double_cover {
+    use super::*;
+
+    #[test]
+    fn test_same_sign() {
+        assert_quaternion_eq!([0.0, 0.6, 0.0, 0.8], [0.0, 0.6, 0.0, 0.8]);
+        assert_quaternion_eq!(
+            [0.0f32, 0.6, 0.0, 0.8],
+            [0.0, 0.6, 0.0, 0.8 + 1e-7],
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn test_opposite_sign() {
+        assert_quaternion_eq!([0.0, 0.6, 0.0, 0.8], [-0.0, -0.6, -0.0, -0.8]);
+        assert_quaternion_eq!(
+            (0.5, 0.5, 0.5, 0.5),
+            (-0.5, -0.5, -0.5, -0.5 + 1e-9),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn test_mixed_sign() {
+        assert_quaternion_ne!([0.5, 0.5, 0.5, 0.5], [-0.5, 0.5, -0.5, 0.5]);
+        assert!(!quaternion_eq!([0.5, 0.5, 0.5, 0.5], [0.5, -0.5, 0.5, 0.5]));
+    }
+
+    #[test]
+    fn test_nan() {
+        assert_quaternion_ne!([f64::NAN, 0.0, 0.0, 1.0], [f64::NAN, 0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_comparator() {
+        let cmp = double_cover(Relative::default().max_relative(1e-9));
+        assert!(cmp.eq(
+            &[1.0, 2.0, 3.0, 4.0],
+            &[-1.0, -2.0, -3.0, -4.0 * (1.0 + 1e-12)]
+        ));
+        assert!(cmp.ne(
+            &[1.0, 2.0, 3.0, 4.0],
+            &[-1.0, -2.0, -3.0, -4.0 * (1.0 + 1e-6)]
+        ));
+
+        let cmp = double_cover(Ulps::default().max_ulps(4));
+        assert_approx_eq!((0.0f32, 0.0, 1.0, 0.0), (0.0, 0.0, -1.0, -0.0), using = cmp);
+    }
+
+    #[test]
+    fn test_references() {
+        let q = [0.0, 0.0, 0.0, 1.0];
+        let r = [0.0, 0.0, 0.0, -1.0];
+        assert_approx_eq!(&q, &r, using = double_cover(Relative::default()));
+    }
+}
+
+#[cfg(feature = "std")]
+mod rotation_angle {
+    use approx::quaternion::{rotation_angle, RotationAngle};
+    use approx::Comparator;
+
+    #[test]
+    fn test_angle() {
+        let half = core::f64::consts::FRAC_1_SQRT_2;
+        let angle = rotation_angle(&[0.0, 0.0, 0.0, 1.0], &[0.0, 0.0, half, half]);
+        assert_relative_eq!(angle, core::f64::consts::FRAC_PI_2, max_relative = 1e-12);
+
+        let angle = rotation_angle(&[0.0, 0.0, 0.0, 1.0], &[0.0, 0.0, -half, -half]);
+        assert_relative_eq!(angle, core::f64::consts::FRAC_PI_2, max_relative = 1e-12);
+
+        let angle = rotation_angle(&[0.0, 0.0, 0.0, 1.0], &[1.0, 0.0, 0.0, 0.0]);
+        assert_relative_eq!(angle, core::f64::consts::PI, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn test_unnormalized() {
+        assert_rotation_eq!([0.0, 0.0, 0.0, 2.0], [0.0, 0.0, 0.0, -0.5]);
+        assert_rotation_eq!(
+            [1.0, 2.0, 3.0, 4.0],
+            [-2.0, -4.0, -6.0, -8.0],
+            max_angle = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_small_angle() {
+        // A small rotation about the z axis, by an angle of 2e-9.
+        let q = [0.0, 0.0, 1e-9, 1.0];
+        assert_rotation_eq!([0.0, 0.0, 0.0, 1.0], q, max_angle = 3e-9);
+        assert_rotation_ne!([0.0, 0.0, 0.0, 1.0], q, max_angle = 1e-9);
+        assert_relative_eq!(
+            rotation_angle(&[0.0, 0.0, 0.0, 1.0], &q),
+            2e-9,
+            max_relative = 1e-6
+        );
+    }
+
+    #[test]
+    fn test_zero() {
+        assert!(rotation_angle(&[0.0f64, 0.0, 0.0, 0.0], &[0.0, 0.0, 0.0, 1.0]).is_nan());
+        assert_rotation_ne!([0.0, 0.0, 0.0, 0.0], [0.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_comparator() {
+        let cmp = RotationAngle::default().max_angle(1e-6f32);
+        assert!(Comparator::eq(
+            &cmp,
+            &(0.0, 0.0, 0.0, 1.0),
+            &(0.0, 0.0, 0.0, -1.0)
+        ));
+        assert_approx_eq!([0.0f32, 0.0, 0.0, 1.0], [0.0, 0.0, 1e-8, -1.0], using = cmp);
+    }
+}
+
+#[cfg(feature = "mint")]
+mod mint {
+    extern crate mint;
+
+    use self::mint::{Quaternion, Vector3};
+
+    fn quaternion(x: f64, y: f64, z: f64, s: f64) -> Quaternion<f64> {
+        Quaternion {
+            v: Vector3 { x, y, z },
+            s,
+        }
+    }
+
+    #[test]
+    fn test_double_cover() {
+        assert_quaternion_eq!(
+            quaternion(0.0, 0.6, 0.0, 0.8),
+            quaternion(0.0, -0.6, 0.0, -0.8)
+        );
+        assert_quaternion_ne!(
+            quaternion(0.0, 0.6, 0.0, 0.8),
+            quaternion(0.0, 0.8, 0.0, 0.6)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_rotation() {
+        assert_rotation_eq!(
+            quaternion(0.0, 0.6, 0.0, 0.8),
+            quaternion(0.0, -0.6, 0.0, -0.8 - 1e-12),
+            max_angle = 1e-9
+        );
+    }
+}
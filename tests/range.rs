@@ -0,0 +1,96 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![no_std]
+
+#[macro_use]
+extern crate approx;
+
+mod test_abs_diff {
+    #[test]
+    fn test_in_range() {
+        assert_abs_diff_in_range!(0.5, 0.0..=1.0);
+        assert_abs_diff_in_range!(0.0, 0.0..=1.0);
+        assert_abs_diff_in_range!(1.0 + 1e-12, 0.0..=1.0, epsilon = 1e-9);
+        assert_abs_diff_in_range!(-1e-12, 0.0..=1.0, epsilon = 1e-9,);
+
+        assert!(!abs_diff_in_range!(1.0 + 1e-6, 0.0..=1.0, epsilon = 1e-9));
+        assert!(!abs_diff_in_range!(-1e-6, 0.0..=1.0, epsilon = 1e-9));
+    }
+
+    #[test]
+    #[should_panic(expected = "below the lower bound by 0.5")]
+    fn test_in_range_panic_lower() {
+        assert_abs_diff_in_range!(-0.5, 0.0..=1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "above the upper bound by 0.5")]
+    fn test_in_range_panic_upper() {
+        assert_abs_diff_in_range!(1.5, 0.0..=1.0, epsilon = 0.25);
+    }
+}
+
+mod test_approx {
+    #[test]
+    fn test_in_range() {
+        assert_approx_in_range!(0.5, 0.0..=1.0);
+        assert_approx_in_range!(1.0 + 1e-12, 0.0..=1.0, epsilon = 1e-9);
+        assert_approx_in_range!(-1e-12, 0.0..=1.0, epsilon = 1e-9,);
+    }
+
+    #[test]
+    #[should_panic(expected = "assert_approx_in_range!(1.5, 0.0..=1.0, epsilon = 0.25)")]
+    fn test_in_range_panic() {
+        assert_approx_in_range!(1.5, 0.0..=1.0, epsilon = 0.25);
+    }
+}
+
+mod test_relative {
+    #[test]
+    fn test_in_range() {
+        assert_relative_in_range!(0.1 + 0.2, 0.0..=0.3);
+        assert_relative_in_range!(100000001.0f32, 0.0..=100000000.0f32);
+
+        assert!(!relative_in_range!(10001.0f32, 0.0..=10000.0f32));
+        assert!(relative_in_range!(
+            10001.0f32,
+            0.0..=10000.0f32,
+            max_relative = 1e-3
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "assert_relative_in_range!(2.0, 0.0..=1.0)")]
+    fn test_in_range_panic() {
+        assert_relative_in_range!(2.0, 0.0..=1.0);
+    }
+}
+
+mod test_ulps {
+    #[test]
+    fn test_in_range() {
+        assert_ulps_in_range!(0.1 + 0.2, 0.0..=0.3);
+        assert_ulps_in_range!(-0.0, 0.0..=1.0);
+
+        assert!(!ulps_in_range!(1.01, 0.0..=1.0));
+        assert!(ulps_in_range!(1.01, 0.0..=1.0, epsilon = 0.1, max_ulps = 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "assert_ulps_in_range!(-1.0, 0.0..=1.0, max_ulps = 4)")]
+    fn test_in_range_panic() {
+        assert_ulps_in_range!(-1.0, 0.0..=1.0, max_ulps = 4);
+    }
+}
@@ -0,0 +1,226 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test rationals and arbitrary-precision integers
+
+#![cfg(any(feature = "num-rational", feature = "num-bigint"))]
+#![no_std]
+
+#[macro_use]
+extern crate approx;
+
+#[cfg(feature = "num-rational")]
+mod rational {
+    extern crate num_rational;
+
+    use self::num_rational::Ratio;
+
+    fn ratio(numer: i64, denom: i64) -> Ratio<i64> {
+        Ratio::new(numer, denom)
+    }
+
+    #[test]
+    fn test_abs_diff() {
+        assert_abs_diff_eq!(ratio(1, 3), ratio(2, 6));
+        assert_abs_diff_ne!(ratio(1, 3), ratio(333, 1000));
+        assert_abs_diff_eq!(ratio(1, 3), ratio(333, 1000), epsilon = ratio(1, 1000));
+        assert_abs_diff_eq!(ratio(333, 1000), ratio(1, 3), epsilon = ratio(1, 1000));
+        assert_abs_diff_ne!(ratio(1, 3), ratio(333, 1000), epsilon = ratio(1, 3001));
+        assert_abs_diff_eq!(ratio(-1, 3), ratio(1, 3), epsilon = ratio(2, 3));
+    }
+
+    #[test]
+    fn test_unsigned() {
+        assert_abs_diff_eq!(
+            Ratio::new(1u32, 2),
+            Ratio::new(3u32, 4),
+            epsilon = Ratio::new(1, 4)
+        );
+        assert_abs_diff_ne!(
+            Ratio::new(3u32, 4),
+            Ratio::new(1u32, 2),
+            epsilon = Ratio::new(1, 5)
+        );
+    }
+
+    #[test]
+    fn test_relative() {
+        assert_relative_eq!(ratio(1, 3), ratio(2, 6));
+        assert_relative_ne!(ratio(1000, 1), ratio(1001, 1));
+        assert_relative_eq!(
+            ratio(1000, 1),
+            ratio(1001, 1),
+            max_relative = ratio(1, 1001)
+        );
+        assert_relative_eq!(
+            ratio(-1001, 1),
+            ratio(-1000, 1),
+            max_relative = ratio(1, 1001)
+        );
+        assert_relative_ne!(
+            ratio(1000, 1),
+            ratio(1001, 1),
+            max_relative = ratio(1, 1002)
+        );
+        assert_relative_ne!(ratio(-1, 1), ratio(1, 1), max_relative = ratio(1, 1));
+        assert_relative_eq!(
+            ratio(0, 1),
+            ratio(1, 1_000_000),
+            epsilon = ratio(1, 1_000_000),
+            max_relative = ratio(0, 1)
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        // The common denominator of the difference overflows, so it's never within tolerance
+        assert_abs_diff_ne!(
+            ratio(1, 4_000_000_007),
+            ratio(1, 4_000_000_009),
+            epsilon = ratio(1, 1000)
+        );
+        assert_relative_ne!(ratio(i64::MAX, 1), ratio(i64::MIN + 1, 1));
+
+        // The tolerance overflows, so it can only exceed the difference
+        assert_relative_eq!(
+            ratio(i64::MAX, 1),
+            ratio(i64::MAX - 1, 1),
+            max_relative = ratio(2, 3)
+        );
+        assert_relative_eq!(
+            ratio(i64::MIN, 1),
+            ratio(i64::MIN + 1, 1),
+            max_relative = ratio(1, 2)
+        );
+        assert_relative_ne!(
+            ratio(i64::MIN, 1),
+            ratio(i64::MIN + 1, 1),
+            max_relative = ratio(0, 1)
+        );
+    }
+
+    #[test]
+    fn test_against_float() {
+        let exact = ratio(1, 10);
+        let approx = Ratio::<i64>::approximate_float(0.1f64).unwrap();
+        assert_relative_eq!(exact, approx, max_relative = ratio(1, 1_000_000_000));
+    }
+}
+
+#[cfg(all(feature = "num-bigint", feature = "std"))]
+mod bigint {
+    extern crate num_bigint;
+    extern crate std;
+
+    use self::num_bigint::{BigInt, BigUint};
+
+    #[test]
+    fn test_big_int() {
+        let a = BigInt::from(10).pow(40);
+        let b = &a + 7;
+        assert_abs_diff_eq!(a, a.clone());
+        assert_abs_diff_ne!(a, b);
+        assert_abs_diff_eq!(a, b, epsilon = BigInt::from(7));
+        assert_abs_diff_eq!(-&a, -&b, epsilon = BigInt::from(7));
+        assert_abs_diff_ne!(b, a, epsilon = BigInt::from(6));
+    }
+
+    #[test]
+    fn test_big_uint() {
+        let a = BigUint::from(10u32).pow(40);
+        let b = &a + 7u32;
+        assert_abs_diff_eq!(b, a, epsilon = BigUint::from(7u32));
+        assert_abs_diff_ne!(a, b, epsilon = BigUint::from(6u32));
+    }
+
+    #[test]
+    fn test_big_int_relative() {
+        let a = BigInt::from(10).pow(40);
+        let b = &a * 2;
+        assert_relative_eq!(a, a.clone());
+        assert_relative_ne!(a, b);
+        assert_relative_eq!(a, b, max_relative = BigInt::from(1));
+        assert_relative_eq!(-&a, -&b, max_relative = BigInt::from(1));
+        assert_relative_ne!(-&a, b.clone(), max_relative = BigInt::from(1));
+        assert_relative_eq!(-&a, b.clone(), max_relative = BigInt::from(2));
+        assert_relative_eq!(a, &a + 7, epsilon = BigInt::from(7));
+    }
+
+    #[test]
+    fn test_big_uint_relative() {
+        let a = BigUint::from(10u32).pow(40);
+        let b = &a * 2u32;
+        assert_relative_eq!(a, a.clone());
+        assert_relative_ne!(b, a);
+        assert_relative_eq!(b, a, max_relative = BigUint::from(1u32));
+        assert_relative_eq!(a, &a + 7u32, epsilon = BigUint::from(7u32));
+        assert_relative_ne!(a, &a + 7u32, epsilon = BigUint::from(6u32));
+    }
+}
+
+#[cfg(all(feature = "num-bigint", feature = "num-rational", feature = "std"))]
+mod big_rational {
+    extern crate num_bigint;
+    extern crate num_rational;
+    extern crate std;
+
+    use self::num_bigint::BigInt;
+    use self::num_rational::Ratio;
+
+    fn ratio(numer: i64, denom: i64) -> Ratio<BigInt> {
+        Ratio::new(BigInt::from(numer), BigInt::from(denom))
+    }
+
+    #[test]
+    fn test_big_rational() {
+        assert_abs_diff_eq!(ratio(1, 3), ratio(333, 1000), epsilon = ratio(1, 1000));
+        assert_relative_eq!(
+            ratio(1000, 1),
+            ratio(1001, 1),
+            max_relative = ratio(1, 1001)
+        );
+        assert_relative_ne!(
+            ratio(1000, 1),
+            ratio(1001, 1),
+            max_relative = ratio(1, 1002)
+        );
+    }
+
+    // The cross-multiplications in these comparisons overflow `i64`
+    #[test]
+    fn test_abs_diff_large_denominators() {
+        let a = ratio(1, 4_000_000_007);
+        let b = ratio(1, 4_000_000_009);
+        assert_abs_diff_eq!(a, b, epsilon = ratio(1, 1000));
+        assert_abs_diff_ne!(a, b, epsilon = ratio(1, i64::MAX));
+        assert_abs_diff_eq!(
+            ratio(i64::MAX, 3),
+            ratio(i64::MAX - 1, 3),
+            epsilon = ratio(1, 3)
+        );
+    }
+
+    #[test]
+    fn test_relative_large_denominators() {
+        let a = ratio(1, 4_000_000_007);
+        let b = ratio(1, 4_000_000_009);
+        assert_relative_eq!(a, b, max_relative = ratio(1, 1_000_000_000));
+        assert_relative_ne!(a, b, max_relative = ratio(1, 10_000_000_000));
+        assert_relative_eq!(
+            ratio(i64::MAX, 4_000_000_007),
+            ratio(i64::MAX, 4_000_000_009),
+            max_relative = ratio(1, 1_000_000_000)
+        );
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test serialization of the comparison parameters
+
+#![cfg(all(feature = "serde", feature = "std"))]
+
+extern crate approx;
+extern crate toml;
+
+use std::collections::BTreeMap;
+
+use approx::{AbsDiff, Relative, Ulps};
+
+#[test]
+fn test_load_tolerances() {
+    let config = r#"
+        [solver]
+        epsilon = 1e-12
+        max_relative = 1e-9
+
+        [integrator]
+        max_relative = 1e-6
+    "#;
+
+    let tolerances: BTreeMap<String, Relative<f64>> = toml::from_str(config).unwrap();
+
+    let solver = tolerances["solver"];
+    assert_eq!(solver.epsilon, 1e-12);
+    assert_eq!(solver.max_relative, 1e-9);
+    assert!(solver.eq(&1.0, &(1.0 + 1e-10)));
+    assert!(solver.ne(&1.0, &(1.0 + 1e-8)));
+
+    let integrator = tolerances["integrator"];
+    assert_eq!(integrator.epsilon, f64::EPSILON);
+    assert_eq!(integrator.max_relative, 1e-6);
+    assert!(integrator.eq(&1.0, &(1.0 + 1e-7)));
+}
+
+#[test]
+fn test_defaults() {
+    let abs_diff: AbsDiff<f32> = toml::from_str("").unwrap();
+    assert_eq!(abs_diff.epsilon, f32::EPSILON);
+
+    let relative: Relative<f64> = toml::from_str("").unwrap();
+    assert_eq!(relative.epsilon, f64::EPSILON);
+    assert_eq!(relative.max_relative, f64::EPSILON);
+
+    let ulps: Ulps<f64> = toml::from_str("epsilon = 0.0").unwrap();
+    assert_eq!(ulps.epsilon, 0.0);
+    assert_eq!(ulps.max_ulps, 4);
+}
+
+#[test]
+fn test_round_trip() {
+    let ulps = Ulps::<f64>::default().epsilon(1e-12).max_ulps(8);
+    let text = toml::to_string(&ulps).unwrap();
+    assert_eq!(text, "epsilon = 0.000000000001\nmax_ulps = 8\n");
+
+    let loaded: Ulps<f64> = toml::from_str(&text).unwrap();
+    assert_eq!(loaded.epsilon, ulps.epsilon);
+    assert_eq!(loaded.max_ulps, ulps.max_ulps);
+
+    let abs_diff = AbsDiff::<f32>::default().epsilon(0.5);
+    let loaded: AbsDiff<f32> = toml::from_str(&toml::to_string(&abs_diff).unwrap()).unwrap();
+    assert!(loaded.eq(&1.0, &1.5));
+}
+
+#[test]
+fn test_errors() {
+    let err = toml::from_str::<Relative<f64>>("max_relaitve = 1e-9").unwrap_err();
+    assert!(
+        err.to_string().contains("unknown field `max_relaitve`"),
+        "{}",
+        err
+    );
+
+    let err = toml::from_str::<Ulps<f64>>("max_ulps = -1").unwrap_err();
+    assert!(err.to_string().contains("max_ulps"), "{}", err);
+
+    let err = toml::from_str::<AbsDiff<f64>>("epsilon = \"small\"").unwrap_err();
+    assert!(err.to_string().contains("epsilon"), "{}", err);
+}
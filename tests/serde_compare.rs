@@ -0,0 +1,253 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test structural comparison of serializable values
+
+#![cfg(all(feature = "serde", feature = "std"))]
+
+#[macro_use]
+extern crate approx;
+extern crate serde;
+
+use std::collections::{BTreeMap, HashMap};
+
+use approx::serde::{Mismatch, Segment};
+use approx::Tolerance;
+use serde::ser::{SerializeStruct, SerializeStructVariant};
+use serde::{Serialize, Serializer};
+
+struct Point {
+    x: f64,
+    y: f32,
+}
+
+impl Serialize for Point {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Point", 2)?;
+        state.serialize_field("x", &self.x)?;
+        state.serialize_field("y", &self.y)?;
+        state.end()
+    }
+}
+
+enum Shape {
+    Empty,
+    Circle(f64),
+    Polygon { points: Vec<Point> },
+}
+
+impl Serialize for Shape {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            Shape::Empty => serializer.serialize_unit_variant("Shape", 0, "Empty"),
+            Shape::Circle(ref radius) => {
+                serializer.serialize_newtype_variant("Shape", 1, "Circle", radius)
+            }
+            Shape::Polygon { ref points } => {
+                let mut state = serializer.serialize_struct_variant("Shape", 2, "Polygon", 1)?;
+                state.serialize_field("points", points)?;
+                state.end()
+            }
+        }
+    }
+}
+
+fn path(mismatch: &Mismatch) -> String {
+    match *mismatch {
+        Mismatch::Value { ref path, .. } => path.to_string(),
+        Mismatch::Serialize(ref message) => panic!("unexpected error: {}", message),
+    }
+}
+
+fn polygon(points: &[(f64, f32)]) -> Shape {
+    Shape::Polygon {
+        points: points.iter().map(|&(x, y)| Point { x, y }).collect(),
+    }
+}
+
+#[test]
+fn test_floats() {
+    assert!(approx::serde::abs_diff_eq(&1.0, &1.05, 0.1).is_ok());
+    assert!(approx::serde::abs_diff_eq(&1.0, &1.5, 0.1).is_err());
+    assert!(approx::serde::relative_eq(&1e6, &(1e6 + 0.1), 0.0, 1e-6).is_ok());
+    assert!(approx::serde::relative_eq(&1e6, &(1e6 + 10.0), 0.0, 1e-6).is_err());
+    assert!(approx::serde::ulps_eq(&2.0, &(2.0 + 2.0 * f64::EPSILON), 0.0, 4).is_ok());
+    assert!(approx::serde::ulps_eq(&2.0, &(2.0 + 2.0 * f64::EPSILON), 0.0, 0).is_err());
+}
+
+#[test]
+fn test_f32_uses_its_own_precision() {
+    // One ULP apart as f32, but billions of ULPs apart as f64
+    let left = 2.0f32;
+    let right = 2.0f32 + 2.0 * f32::EPSILON;
+    assert!(approx::serde::ulps_eq(&left, &right, 0.0, 1).is_ok());
+    assert!(approx::serde::ulps_eq(&left, &right, 0.0, 0).is_err());
+
+    // The default epsilon is that of `f32`
+    assert!(approx::serde::compare(&1.0f32, &1.0000001f32, Tolerance::default()).is_ok());
+    assert!(approx::serde::compare(&1.0f64, &1.0000001f64, Tolerance::default()).is_err());
+}
+
+#[test]
+fn test_mixed_precision() {
+    assert!(approx::serde::abs_diff_eq(&0.1f32, &0.1f64, 1e-6).is_ok());
+    assert!(approx::serde::abs_diff_eq(&0.1f64, &0.1f32, 1e-12).is_err());
+}
+
+#[test]
+fn test_exact_values() {
+    assert!(approx::serde::abs_diff_eq(&(1, "a", true), &(1, "a", true), 1.0).is_ok());
+    assert!(approx::serde::abs_diff_eq(&1u8, &1i64, 1.0).is_ok());
+    assert!(approx::serde::abs_diff_eq(&u128::MAX, &u128::MAX, 1.0).is_ok());
+
+    let mismatch = approx::serde::abs_diff_eq(&(1, "a"), &(1, "b"), 1.0).unwrap_err();
+    assert_eq!(path(&mismatch), "[1]");
+
+    // Integers are never compared approximately
+    assert!(approx::serde::abs_diff_eq(&1, &2, 10.0).is_err());
+    assert!(approx::serde::abs_diff_eq(&1, &1.0, 10.0).is_err());
+}
+
+#[test]
+fn test_sequences() {
+    let left = vec![1.0, 2.0, 3.0];
+    assert!(approx::serde::abs_diff_eq(&left, &[1.0, 2.0, 3.0000001], 1e-6).is_ok());
+
+    let mismatch = approx::serde::abs_diff_eq(&left, &[1.0, 2.5, 3.0], 1e-6).unwrap_err();
+    assert_eq!(
+        mismatch,
+        Mismatch::Value {
+            path: mismatch_path(&mismatch),
+            left: Some("2.0".to_string()),
+            right: Some("2.5".to_string()),
+        }
+    );
+    assert_eq!(path(&mismatch), "[1]");
+
+    let mismatch = approx::serde::abs_diff_eq(&left, &[1.0, 2.0], 1e-6).unwrap_err();
+    assert_eq!(
+        mismatch.to_string(),
+        "values differ at `[2]`:\n\n    left  = 3.0\n    right is missing"
+    );
+}
+
+fn mismatch_path(mismatch: &Mismatch) -> approx::serde::Path {
+    match *mismatch {
+        Mismatch::Value { ref path, .. } => path.clone(),
+        Mismatch::Serialize(_) => unreachable!(),
+    }
+}
+
+#[test]
+fn test_maps_ignore_order() {
+    let mut left = HashMap::new();
+    let mut right = BTreeMap::new();
+    for i in 0..20 {
+        left.insert(format!("key{}", i), i as f64 * 0.1);
+        right.insert(format!("key{}", i), i as f64 / 10.0);
+    }
+    assert!(approx::serde::relative_eq(&left, &right, 1e-12, 1e-12).is_ok());
+
+    right.insert("key7".to_string(), 0.8);
+    let mismatch = approx::serde::relative_eq(&left, &right, 1e-12, 1e-12).unwrap_err();
+    assert_eq!(path(&mismatch), r#"["key7"]"#);
+    assert_eq!(
+        mismatch_path(&mismatch).segments(),
+        &[Segment::Key("key7".to_string())]
+    );
+
+    right.remove("key7");
+    let mismatch = approx::serde::relative_eq(&left, &right, 1e-12, 1e-12).unwrap_err();
+    assert_eq!(path(&mismatch), r#"["key7"]"#);
+    assert!(mismatch.to_string().ends_with("right is missing"));
+
+    let mut extra = BTreeMap::new();
+    extra.insert(1, 1.0);
+    extra.insert(2, 2.0);
+    let mismatch =
+        approx::serde::abs_diff_eq(&BTreeMap::<i32, f64>::new(), &extra, 0.0).unwrap_err();
+    assert_eq!(path(&mismatch), r#"["1"]"#);
+    assert!(mismatch.to_string().contains("left is missing"));
+}
+
+#[test]
+fn test_structs_and_enums() {
+    let left = vec![
+        Shape::Empty,
+        Shape::Circle(1.0),
+        polygon(&[(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)]),
+    ];
+    let right = vec![
+        Shape::Empty,
+        Shape::Circle(1.0 + 1e-12),
+        polygon(&[(0.0, 0.0), (1.0, 0.0), (0.0, 1.0000001)]),
+    ];
+    assert_serde_eq!(left, right, epsilon = 1e-6);
+
+    let mismatch = approx::serde::abs_diff_eq(&left, &right, 1e-9).unwrap_err();
+    assert_eq!(path(&mismatch), "[2].points[2].y");
+    assert_eq!(mismatch_path(&mismatch).json_pointer(), "/2/points/2/y");
+
+    let mismatch = approx::serde::abs_diff_eq(&Shape::Empty, &Shape::Circle(0.0), 1.0).unwrap_err();
+    assert_eq!(
+        mismatch.to_string(),
+        "values differ:\n\n    left  = Empty\n    right = Circle(0.0)"
+    );
+
+    let mismatch =
+        approx::serde::abs_diff_eq(&polygon(&[]), &polygon(&[(1.0, 2.0)]), 1.0).unwrap_err();
+    assert_eq!(
+        mismatch.to_string(),
+        "values differ at `.points[0]`:\n\n    left is missing\n    right = { x: 1.0, y: 2.0 }"
+    );
+}
+
+#[test]
+fn test_options() {
+    assert!(approx::serde::abs_diff_eq(&Some(1.0), &Some(1.05), 0.1).is_ok());
+    assert!(approx::serde::abs_diff_eq(&Some(1.0), &None::<f64>, 0.1).is_err());
+    assert!(approx::serde::abs_diff_eq(&None::<f64>, &None::<f32>, 0.1).is_ok());
+}
+
+#[test]
+fn test_json_pointer_escapes() {
+    let mut left = BTreeMap::new();
+    left.insert("a/b~c", 1.0);
+    let mut right = BTreeMap::new();
+    right.insert("a/b~c", 2.0);
+
+    let mismatch = approx::serde::abs_diff_eq(&left, &right, 0.1).unwrap_err();
+    assert_eq!(mismatch_path(&mismatch).json_pointer(), "/a~1b~0c");
+}
+
+#[test]
+fn test_serialize_error() {
+    struct Broken;
+
+    impl Serialize for Broken {
+        fn serialize<S: Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+            Err(serde::ser::Error::custom("broken"))
+        }
+    }
+
+    let mismatch = approx::serde::abs_diff_eq(&Broken, &1.0, 0.1).unwrap_err();
+    assert_eq!(mismatch, Mismatch::Serialize("broken".to_string()));
+    assert_eq!(mismatch.to_string(), "failed to serialize value: broken");
+}
+
+#[test]
+#[should_panic(expected = "values differ at `[1]`")]
+fn test_assert_serde_eq() {
+    assert_serde_eq!(vec![1.0, 2.0], vec![1.0, 3.0], max_relative = 1e-9);
+}
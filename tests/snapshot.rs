@@ -0,0 +1,205 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test golden-file snapshots
+
+#![cfg(feature = "std")]
+
+#[macro_use]
+extern crate approx;
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard};
+
+use approx::snapshot::{Difference, Mismatch};
+use approx::Relative;
+
+/// Serialises the tests, as they depend on the `APPROX_UPDATE` environment variable.
+static LOCK: Mutex<()> = Mutex::new(());
+
+fn lock() -> MutexGuard<'static, ()> {
+    LOCK.lock().unwrap_or_else(|error| error.into_inner())
+}
+
+fn snapshot_path(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("approx-snapshot-{}", std::process::id()));
+    let path = dir.join(name).join("values.txt");
+    let _ = fs::remove_file(&path);
+    path
+}
+
+#[test]
+fn test_written_on_first_run() {
+    let _lock = lock();
+    let path = snapshot_path("first_run");
+
+    approx::snapshot::relative_eq(&path, &[0.1, 1e-20, -3.0], 0.0, 1e-12).unwrap();
+    assert_eq!(fs::read_to_string(&path).unwrap(), "0.1\n1e-20\n-3.0\n");
+
+    approx::snapshot::relative_eq(&path, &[0.1, 1e-20, -3.0], 0.0, 1e-12).unwrap();
+}
+
+#[test]
+fn test_round_trip() {
+    let _lock = lock();
+    let path = snapshot_path("round_trip");
+    let values: Vec<f64> = (1..100).map(|i| 1.0 / i as f64).collect();
+
+    approx::snapshot::ulps_eq(&path, &values, 0.0, 0).unwrap();
+    approx::snapshot::ulps_eq(&path, &values, 0.0, 0).unwrap();
+
+    let path = snapshot_path("round_trip_f32");
+    let values: Vec<f32> = (1..100).map(|i| 1.0 / i as f32).collect();
+    approx::snapshot::ulps_eq(&path, &values, 0.0, 0).unwrap();
+    approx::snapshot::ulps_eq(&path, &values, 0.0, 0).unwrap();
+}
+
+#[test]
+fn test_differences() {
+    let _lock = lock();
+    let path = snapshot_path("differences");
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, "# hand-written\n1.0\n\n2.0\n3.0\n").unwrap();
+
+    approx::snapshot::abs_diff_eq(&path, &[1.0, 2.05, 3.0], 0.1).unwrap();
+
+    let mismatch = approx::snapshot::abs_diff_eq(&path, &[1.0, 2.5, 3.0, 4.0], 0.1).unwrap_err();
+    assert_eq!(
+        mismatch,
+        Mismatch::Values {
+            path: path.clone(),
+            differences: vec![
+                Difference {
+                    index: 1,
+                    actual: Some(2.5),
+                    expected: Some(2.0),
+                },
+                Difference {
+                    index: 3,
+                    actual: Some(4.0),
+                    expected: None,
+                },
+            ],
+        }
+    );
+    assert_eq!(
+        mismatch.to_string(),
+        format!(
+            "2 elements differ from the snapshot `{}`:
+
+    index   actual                      expected
+    1       2.5                         2.0
+    3       4.0                         -
+
+    Run with APPROX_UPDATE=1 to update the snapshot.",
+            path.display()
+        )
+    );
+
+    // The snapshot is left unchanged
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "# hand-written\n1.0\n\n2.0\n3.0\n"
+    );
+}
+
+#[test]
+fn test_many_differences() {
+    let _lock = lock();
+    let path = snapshot_path("many_differences");
+    approx::snapshot::abs_diff_eq(&path, &[0.0; 30], 0.0).unwrap();
+
+    let mismatch = approx::snapshot::abs_diff_eq(&path, &[1.0; 30], 0.0).unwrap_err();
+    assert!(mismatch.to_string().starts_with("30 elements differ"));
+    assert!(mismatch.to_string().contains("\n    ... and 10 more\n"));
+}
+
+#[test]
+fn test_update() {
+    let _lock = lock();
+    let path = snapshot_path("update");
+    approx::snapshot::abs_diff_eq(&path, &[1.0, 2.0], 0.0).unwrap();
+    assert!(approx::snapshot::abs_diff_eq(&path, &[1.0, 3.0], 0.0).is_err());
+
+    env::set_var("APPROX_UPDATE", "1");
+    let result = approx::snapshot::abs_diff_eq(&path, &[1.0, 3.0], 0.0);
+    env::remove_var("APPROX_UPDATE");
+    result.unwrap();
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), "1.0\n3.0\n");
+    approx::snapshot::abs_diff_eq(&path, &[1.0, 3.0], 0.0).unwrap();
+}
+
+#[test]
+fn test_decode_error() {
+    let _lock = lock();
+    let path = snapshot_path("decode_error");
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, "1.0\n\nnot a number\n").unwrap();
+
+    let mismatch = approx::snapshot::abs_diff_eq(&path, &[1.0], 0.0).unwrap_err();
+    assert_eq!(
+        mismatch,
+        Mismatch::Decode {
+            path: path.clone(),
+            line: 3,
+        }
+    );
+}
+
+#[test]
+fn test_io_error() {
+    let _lock = lock();
+    let path = snapshot_path("io_error");
+    fs::create_dir_all(&path).unwrap();
+
+    let mismatch = approx::snapshot::abs_diff_eq(&path, &[1.0], 0.0).unwrap_err();
+    fs::remove_dir(&path).unwrap();
+    match mismatch {
+        Mismatch::Io {
+            path: error_path, ..
+        } => assert_eq!(error_path, path),
+        mismatch => panic!("unexpected mismatch: {:?}", mismatch),
+    }
+}
+
+#[test]
+fn test_comparator() {
+    let _lock = lock();
+    let path = snapshot_path("comparator");
+    approx::snapshot::compare(&path, &[1e6], Relative::default()).unwrap();
+    approx::snapshot::compare(&path, &[1000000.1], Relative::default().max_relative(1e-6)).unwrap();
+    assert!(approx::snapshot::compare(&path, &[1000000.1], Relative::default()).is_err());
+}
+
+#[test]
+fn test_assert() {
+    let _lock = lock();
+    let path = snapshot_path("assert");
+    assert_snapshot_abs_diff_eq!(&path, vec![1.0, 2.0]);
+    assert_snapshot_abs_diff_eq!(&path, vec![1.0, 2.05], epsilon = 0.1);
+    assert_snapshot_relative_eq!(&path, [1.0, 2.0 * (1.0 + f64::EPSILON)]);
+    assert_snapshot_ulps_eq!(&path, [1.0, 2.0], max_ulps = 0,);
+}
+
+#[test]
+#[should_panic(expected = "1 element differs from the snapshot")]
+fn test_assert_failure() {
+    let _lock = lock();
+    let path = snapshot_path("assert_failure");
+    assert_snapshot_relative_eq!(&path, [1.0, 2.0]);
+    assert_snapshot_relative_eq!(&path, [1.0, 2.5]);
+}
@@ -0,0 +1,122 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test approximate comparison of text containing numbers
+
+#![no_std]
+
+#[macro_use]
+extern crate approx;
+
+use approx::text::Mismatch;
+use approx::Relative;
+
+#[test]
+fn test_numbers() {
+    assert!(approx::text::abs_diff_eq("1.0 2.0", "1.05 2.0", 0.1).is_ok());
+    assert!(approx::text::abs_diff_eq("1.0 2.0", "1.5 2.0", 0.1).is_err());
+    assert!(approx::text::relative_eq("1e6", "1000000.1", 0.0, 1e-6).is_ok());
+    assert!(approx::text::relative_eq("1e6", "1000010", 0.0, 1e-6).is_err());
+    assert!(approx::text::ulps_eq("0.30000000000000004", "0.3", 0.0, 1).is_ok());
+    assert!(approx::text::ulps_eq("0.30000000000000004", "0.3", 0.0, 0).is_err());
+}
+
+#[test]
+fn test_number_formats() {
+    assert!(approx::text::abs_diff_eq("1 -2 +3", "1.0 -2.00 3e0", 0.0).is_ok());
+    assert!(approx::text::abs_diff_eq(".5 5. 1E-3 2e+2", "0.5 5 0.001 200", 1e-12).is_ok());
+    assert!(approx::text::abs_diff_eq("-0.5", "0.5", 0.1).is_err());
+}
+
+#[test]
+fn test_words() {
+    assert!(approx::text::abs_diff_eq("x=1.5, y=2", "x=1.50, y=2.0", 0.0).is_ok());
+    assert!(approx::text::abs_diff_eq("x=1.5", "y=1.5", 0.0).is_err());
+
+    // Numbers must start and end at word boundaries
+    assert!(approx::text::abs_diff_eq("v1", "v1.0", 0.0).is_err());
+    assert!(approx::text::abs_diff_eq("3rd", "3.0rd", 0.0).is_err());
+    assert!(approx::text::abs_diff_eq("1.5.", "1.50.", 0.0).is_ok());
+    assert!(approx::text::abs_diff_eq("x1 y_2", "x1 y_2", 0.0).is_ok());
+    assert!(approx::text::abs_diff_eq("1.2.3", "1.2.3", 0.0).is_ok());
+    assert!(approx::text::abs_diff_eq("1.2.3", "1.20.3", 0.0).is_err());
+}
+
+#[test]
+fn test_whitespace() {
+    assert!(approx::text::abs_diff_eq("a   1.5\tb", "a 1.50 b", 0.0).is_ok());
+    assert!(approx::text::abs_diff_eq("a\r\nb\n", "a\nb\n", 0.0).is_ok());
+    assert!(approx::text::abs_diff_eq("1 2", "12", 0.0).is_err());
+    assert!(approx::text::abs_diff_eq("a\nb", "a b", 0.0).is_err());
+}
+
+#[test]
+fn test_mismatch_position() {
+    let actual = "header\n  x: 1.0\n  y: 2.5\n";
+    let expected = "header\n  x: 1.0\n  y: 2.0\n";
+
+    let mismatch = approx::text::relative_eq(actual, expected, 0.0, 1e-6).unwrap_err();
+    assert_eq!(
+        mismatch,
+        Mismatch {
+            line: 3,
+            column: 6,
+            left: Some("2.5"),
+            right: Some("2.0"),
+        }
+    );
+}
+
+#[test]
+fn test_mismatch_unicode_column() {
+    let mismatch = approx::text::abs_diff_eq("θ = 1.0", "θ = 1.5", 0.1).unwrap_err();
+    assert_eq!((mismatch.line, mismatch.column), (1, 5));
+}
+
+#[test]
+fn test_mismatch_length() {
+    let mismatch = approx::text::abs_diff_eq("1 2\n", "1 2\n3", 0.0).unwrap_err();
+    assert_eq!(
+        mismatch,
+        Mismatch {
+            line: 2,
+            column: 1,
+            left: None,
+            right: Some("3"),
+        }
+    );
+
+    let mismatch = approx::text::abs_diff_eq("1 2 3", "1 2", 0.0).unwrap_err();
+    assert_eq!((mismatch.left, mismatch.right), (Some("3"), None));
+}
+
+#[test]
+fn test_comparator() {
+    let cmp = Relative::default().max_relative(1e-3);
+    assert!(approx::text::compare("mean 10.001", "mean 10", cmp).is_ok());
+    assert!(approx::text::compare("mean 10.1", "mean 10", cmp).is_err());
+}
+
+#[test]
+fn test_assert() {
+    assert_text_abs_diff_eq!("a 1.0", "a 1.05", epsilon = 0.1);
+    assert_text_relative_eq!("a 1e6", "a 1000000.1", max_relative = 1e-6);
+    assert_text_ulps_eq!("a 0.30000000000000004", "a 0.3");
+}
+
+#[test]
+#[should_panic(expected = "texts differ at line 2, column 3")]
+fn test_assert_failure() {
+    assert_text_relative_eq!("a\nb 1.0", "a\nb 1.5");
+}
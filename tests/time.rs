@@ -0,0 +1,258 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test durations and time points
+
+#![no_std]
+
+#[macro_use]
+extern crate approx;
+
+mod duration {
+    use core::time::Duration;
+
+    #[test]
+    fn test_basic() {
+        assert_abs_diff_eq!(Duration::from_millis(100), Duration::from_millis(100));
+        assert_abs_diff_ne!(Duration::from_millis(100), Duration::from_millis(101));
+    }
+
+    #[test]
+    fn test_epsilon() {
+        let eps = Duration::from_millis(5);
+        assert_abs_diff_eq!(
+            Duration::from_millis(100),
+            Duration::from_millis(105),
+            epsilon = eps
+        );
+        assert_abs_diff_eq!(
+            Duration::from_millis(105),
+            Duration::from_millis(100),
+            epsilon = eps
+        );
+        assert_abs_diff_ne!(
+            Duration::from_millis(100),
+            Duration::from_millis(106),
+            epsilon = eps
+        );
+        assert_abs_diff_ne!(
+            Duration::from_millis(106),
+            Duration::from_millis(100),
+            epsilon = eps
+        );
+    }
+
+    #[test]
+    fn test_relative() {
+        // One millisecond in seconds is a relative tolerance of 0.1%
+        let rel = Duration::from_millis(1);
+        assert_relative_eq!(
+            Duration::from_secs(10),
+            Duration::from_millis(10_010),
+            max_relative = rel
+        );
+        assert_relative_eq!(
+            Duration::from_millis(10_010),
+            Duration::from_secs(10),
+            max_relative = rel
+        );
+        assert_relative_ne!(
+            Duration::from_secs(10),
+            Duration::from_millis(10_011),
+            max_relative = rel
+        );
+        assert_relative_ne!(Duration::from_secs(10), Duration::from_millis(10_010));
+    }
+
+    #[test]
+    fn test_relative_default() {
+        // The default of one nanosecond is a relative tolerance of one part in a billion
+        assert_relative_eq!(Duration::from_secs(10), Duration::new(10, 10));
+        assert_relative_ne!(Duration::from_secs(10), Duration::new(10, 11));
+    }
+
+    #[test]
+    fn test_relative_epsilon() {
+        assert_relative_eq!(
+            Duration::from_nanos(1),
+            Duration::from_nanos(3),
+            epsilon = Duration::from_nanos(2)
+        );
+        assert_relative_ne!(
+            Duration::from_nanos(1),
+            Duration::from_nanos(3),
+            epsilon = Duration::from_nanos(1),
+            max_relative = Duration::from_millis(1)
+        );
+    }
+
+    #[test]
+    fn test_extremes() {
+        assert_abs_diff_eq!(Duration::MAX, Duration::MAX);
+        assert_abs_diff_ne!(
+            Duration::MAX,
+            Duration::ZERO,
+            epsilon = Duration::from_secs(1)
+        );
+        assert_abs_diff_eq!(Duration::ZERO, Duration::MAX, epsilon = Duration::MAX);
+    }
+}
+
+#[cfg(feature = "std")]
+mod std_time {
+    extern crate std;
+
+    use self::std::time::{Instant, SystemTime, UNIX_EPOCH};
+    use core::time::Duration;
+
+    #[test]
+    fn test_instant() {
+        let start = Instant::now();
+        let later = start + Duration::from_millis(3);
+        assert_abs_diff_eq!(start, later, epsilon = Duration::from_millis(3));
+        assert_abs_diff_eq!(later, start, epsilon = Duration::from_millis(3));
+        assert_abs_diff_ne!(start, later, epsilon = Duration::from_millis(2));
+        assert_abs_diff_ne!(later, start);
+    }
+
+    #[test]
+    fn test_system_time() {
+        let time = UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let later = time + Duration::from_millis(3);
+        assert_abs_diff_eq!(time, later, epsilon = Duration::from_millis(3));
+        assert_abs_diff_eq!(later, time, epsilon = Duration::from_millis(3));
+        assert_abs_diff_ne!(time, later, epsilon = Duration::from_millis(2));
+        assert_abs_diff_ne!(later, time, epsilon = Duration::from_millis(2));
+        assert_abs_diff_eq!(SystemTime::UNIX_EPOCH, UNIX_EPOCH);
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono {
+    extern crate chrono;
+
+    use self::chrono::{NaiveDate, NaiveTime, TimeDelta};
+
+    #[test]
+    fn test_naive() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 28).unwrap();
+        let next = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        assert_abs_diff_eq!(date, next, epsilon = TimeDelta::days(2));
+        assert_abs_diff_ne!(next, date, epsilon = TimeDelta::days(1));
+
+        let time = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+        let later = NaiveTime::from_hms_milli_opt(12, 0, 0, 250).unwrap();
+        assert_abs_diff_eq!(time, later, epsilon = TimeDelta::milliseconds(250));
+        assert_abs_diff_ne!(later, time, epsilon = TimeDelta::milliseconds(249));
+
+        let date_time = date.and_time(time);
+        assert_abs_diff_eq!(
+            date_time,
+            date.and_time(later),
+            epsilon = TimeDelta::seconds(1)
+        );
+        assert_abs_diff_ne!(date_time, next.and_time(time), epsilon = TimeDelta::days(1));
+    }
+
+    #[test]
+    fn test_date_time() {
+        let date_time = NaiveDate::from_ymd_opt(2024, 2, 28)
+            .unwrap()
+            .and_hms_opt(23, 59, 59)
+            .unwrap()
+            .and_utc();
+        let later = date_time + TimeDelta::milliseconds(1500);
+        assert_abs_diff_eq!(date_time, date_time);
+        assert_abs_diff_eq!(date_time, later, epsilon = TimeDelta::seconds(2));
+        assert_abs_diff_ne!(later, date_time, epsilon = TimeDelta::seconds(1));
+    }
+
+    #[test]
+    fn test_time_delta() {
+        assert_abs_diff_eq!(
+            TimeDelta::seconds(-1),
+            TimeDelta::seconds(1),
+            epsilon = TimeDelta::seconds(2)
+        );
+        assert_abs_diff_ne!(
+            TimeDelta::seconds(1),
+            TimeDelta::seconds(-1),
+            epsilon = TimeDelta::seconds(1)
+        );
+        assert_abs_diff_ne!(TimeDelta::MAX, TimeDelta::MIN, epsilon = TimeDelta::MAX);
+    }
+}
+
+#[cfg(feature = "time")]
+mod time {
+    extern crate time;
+
+    use self::time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time};
+
+    #[test]
+    fn test_date_and_time() {
+        let date = Date::from_calendar_date(2024, Month::February, 28).unwrap();
+        let next = Date::from_calendar_date(2024, Month::March, 1).unwrap();
+        assert_abs_diff_eq!(date, next, epsilon = Duration::days(2));
+        assert_abs_diff_ne!(next, date, epsilon = Duration::days(1));
+
+        let time = Time::from_hms(12, 0, 0).unwrap();
+        let later = Time::from_hms_milli(12, 0, 0, 250).unwrap();
+        assert_abs_diff_eq!(time, later, epsilon = Duration::milliseconds(250));
+        assert_abs_diff_ne!(later, time, epsilon = Duration::milliseconds(249));
+
+        let date_time = PrimitiveDateTime::new(date, time);
+        assert_abs_diff_eq!(
+            date_time,
+            PrimitiveDateTime::new(date, later),
+            epsilon = Duration::SECOND
+        );
+        assert_abs_diff_ne!(
+            date_time,
+            PrimitiveDateTime::new(next, time),
+            epsilon = Duration::DAY
+        );
+    }
+
+    #[test]
+    fn test_offset_date_time() {
+        let epoch = OffsetDateTime::UNIX_EPOCH;
+        assert_abs_diff_eq!(epoch, epoch);
+        assert_abs_diff_eq!(
+            epoch,
+            epoch + Duration::milliseconds(1500),
+            epsilon = Duration::seconds(2)
+        );
+        assert_abs_diff_ne!(
+            epoch + Duration::milliseconds(1500),
+            epoch,
+            epsilon = Duration::SECOND
+        );
+    }
+
+    #[test]
+    fn test_duration() {
+        assert_abs_diff_eq!(
+            Duration::seconds(-1),
+            Duration::seconds(1),
+            epsilon = Duration::seconds(2)
+        );
+        assert_abs_diff_ne!(
+            Duration::seconds(1),
+            Duration::seconds(-1),
+            epsilon = Duration::SECOND
+        );
+        assert_abs_diff_ne!(Duration::MAX, Duration::MIN, epsilon = Duration::MAX);
+    }
+}
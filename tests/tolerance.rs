@@ -0,0 +1,201 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test parsing and comparing with tolerances
+
+#[macro_use]
+extern crate approx;
+
+use approx::{ParseToleranceError, Tolerance};
+
+mod parse {
+    use super::*;
+
+    #[test]
+    fn test_single() {
+        let abs: Tolerance = "1e-12abs".parse().unwrap();
+        assert_eq!(abs, Tolerance::default().epsilon(1e-12));
+
+        let rel: Tolerance = "1e-9rel".parse().unwrap();
+        assert_eq!(rel, Tolerance::default().max_relative(1e-9));
+
+        let ulps: Tolerance = "4ulps".parse().unwrap();
+        assert_eq!(ulps, Tolerance::default().max_ulps(4));
+    }
+
+    #[test]
+    fn test_combined() {
+        let tolerance: Tolerance = "1e-12abs+1e-9rel+4ulps".parse().unwrap();
+        assert_eq!(
+            tolerance,
+            Tolerance::default()
+                .epsilon(1e-12)
+                .max_relative(1e-9)
+                .max_ulps(4)
+        );
+
+        let reordered: Tolerance = " 4ulps + 1e-9rel + 1e-12abs ".parse().unwrap();
+        assert_eq!(reordered, tolerance);
+    }
+
+    #[test]
+    fn test_positive_exponent() {
+        let tolerance: Tolerance = "1e+5abs+1E+2rel".parse().unwrap();
+        assert_eq!(
+            tolerance,
+            Tolerance::default().epsilon(1e5).max_relative(1e2)
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        let tolerance: Tolerance = "default".parse().unwrap();
+        assert_eq!(tolerance, Tolerance::default());
+    }
+
+    #[test]
+    fn test_f32() {
+        let tolerance: Tolerance<f32> = "1e-6abs".parse().unwrap();
+        assert_eq!(tolerance.epsilon, Some(1e-6f32));
+    }
+
+    #[test]
+    fn test_errors() {
+        fn parse(s: &str) -> Result<Tolerance, ParseToleranceError> {
+            s.parse()
+        }
+
+        assert_eq!(parse(""), Err(ParseToleranceError::Empty));
+        assert_eq!(parse("  "), Err(ParseToleranceError::Empty));
+        assert_eq!(parse("1e-9"), Err(ParseToleranceError::MissingUnit));
+        assert_eq!(parse("1e-9abs+"), Err(ParseToleranceError::MissingUnit));
+        assert_eq!(parse("rel"), Err(ParseToleranceError::MissingValue("rel")));
+        assert_eq!(
+            parse("1e-9abs+ulps"),
+            Err(ParseToleranceError::MissingValue("ulps"))
+        );
+        assert_eq!(parse("xabs"), Err(ParseToleranceError::InvalidValue("abs")));
+        assert_eq!(
+            parse("1.5ulps"),
+            Err(ParseToleranceError::InvalidValue("ulps"))
+        );
+        assert_eq!(
+            parse("-1ulps"),
+            Err(ParseToleranceError::InvalidValue("ulps"))
+        );
+        assert_eq!(
+            parse("1e-9rel 4ulps"),
+            Err(ParseToleranceError::MissingSeparator)
+        );
+        assert_eq!(
+            parse("1e-9relx"),
+            Err(ParseToleranceError::MissingSeparator)
+        );
+        assert_eq!(
+            parse("1e-9rel+1e-6rel"),
+            Err(ParseToleranceError::Duplicate("rel"))
+        );
+    }
+
+    #[test]
+    fn test_error_display() {
+        assert_eq!(
+            ParseToleranceError::MissingUnit.to_string(),
+            "missing unit, expected `abs`, `rel` or `ulps`"
+        );
+        assert_eq!(
+            ParseToleranceError::InvalidValue("ulps").to_string(),
+            "invalid value for `ulps`"
+        );
+    }
+}
+
+mod display {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let tolerance = Tolerance::default()
+            .epsilon(1e-12)
+            .max_relative(1e-9)
+            .max_ulps(4);
+        assert_eq!(tolerance.to_string(), "1e-12abs+1e-9rel+4ulps");
+        assert_eq!(Tolerance::<f64>::default().max_ulps(4).to_string(), "4ulps");
+        assert_eq!(Tolerance::<f64>::default().to_string(), "default");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for s in &["1e-12abs", "2.5e-9rel", "1e5abs+4ulps", "default"] {
+            let tolerance: Tolerance = s.parse().unwrap();
+            assert_eq!(tolerance.to_string(), *s);
+            assert_eq!(tolerance.to_string().parse::<Tolerance>(), Ok(tolerance));
+        }
+    }
+}
+
+mod compare {
+    use super::*;
+
+    fn tolerance(s: &str) -> Tolerance {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_abs() {
+        assert!(tolerance("1e-6abs").eq(&1.0, &1.0000001));
+        assert!(tolerance("1e-6abs").ne(&1.0, &1.00001));
+    }
+
+    #[test]
+    fn test_rel() {
+        assert!(tolerance("1e-6rel").eq(&1e6, &1000000.1));
+        assert!(tolerance("1e-9rel").ne(&1e6, &1000000.1));
+    }
+
+    #[test]
+    fn test_ulps() {
+        assert!(tolerance("4ulps").eq(&2.0, &(2.0 + 2.0 * f64::EPSILON)));
+        assert!(tolerance("0ulps").ne(&2.0, &(2.0 + 2.0 * f64::EPSILON)));
+    }
+
+    #[test]
+    fn test_combined() {
+        // Either the relative or the ULPs term is enough
+        assert!(tolerance("1e-9rel+0ulps").eq(&1e6, &1000000.0001));
+        assert!(tolerance("0rel+4ulps").eq(&2.0, &(2.0 + 2.0 * f64::EPSILON)));
+        assert!(tolerance("0rel+0ulps").ne(&2.0, &(2.0 + 2.0 * f64::EPSILON)));
+    }
+
+    #[test]
+    fn test_slices() {
+        let tolerance = tolerance("1e-6abs");
+        assert!(tolerance.eq(&[1.0, 2.0][..], &[1.0000001, 2.0][..]));
+        assert!(tolerance.ne(&[1.0, 2.0][..], &[1.0, 2.1][..]));
+    }
+
+    #[test]
+    fn test_macros() {
+        let tolerance = tolerance("1e-12abs+1e-9rel");
+        assert_approx_eq!(1e6, 1000000.0001, using = tolerance);
+        assert_approx_ne!(1e6, 1000001.0, using = tolerance);
+        let _: bool = approx_eq!(1.0, 1.0, using = tolerance);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_assert_failure() {
+        assert_approx_eq!(1.0, 2.0, using = tolerance("1e-9rel"));
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "std")]
+
+#[macro_use]
+extern crate approx;
+#[cfg(feature = "num-complex")]
+extern crate num_complex;
+
+use approx::unordered::{self, Mismatch};
+use approx::AbsDiff;
+
+#[test]
+fn test_permutation() {
+    let xs = [3.0, 1.0, 2.0];
+    let ys = [1.0, 2.0, 3.0 + 1e-12];
+
+    assert_eq!(unordered::abs_diff_eq(&xs, &ys, 1e-9), Ok(()));
+    assert_eq!(unordered::relative_eq(&xs, &ys, 0.0, 1e-9), Ok(()));
+    assert_eq!(
+        unordered::ulps_eq(xs.to_vec(), ys.to_vec(), 1e-9, 4),
+        Ok(())
+    );
+    assert_unordered_abs_diff_eq!(xs, ys, epsilon = 1e-9);
+    assert_unordered_relative_eq!(xs, ys, max_relative = 1e-9,);
+    assert_unordered_ulps_eq!(xs, ys, epsilon = 1e-9);
+}
+
+#[test]
+fn test_empty() {
+    assert_unordered_abs_diff_eq!(Vec::<f64>::new(), Vec::<f64>::new());
+}
+
+#[test]
+fn test_repeated() {
+    assert_unordered_ulps_eq!([1.0, 1.0, 2.0], [1.0, 2.0, 1.0]);
+    assert!(unordered::abs_diff_eq(&[1.0, 1.0, 2.0], &[1.0, 2.0, 2.0], 0.0).is_err());
+}
+
+#[test]
+fn test_augmenting_path() {
+    // A greedy matching would pair 1.0 with 1.05, leaving 1.1 unmatched.
+    let xs = [1.0, 1.1];
+    let ys = [1.05, 0.96];
+    assert_unordered_abs_diff_eq!(xs, ys, epsilon = 0.06);
+}
+
+#[test]
+fn test_unmatched() {
+    let result = unordered::abs_diff_eq(vec![1.0, 5.0, 2.0], vec![2.0, 1.0, 7.0], 0.1);
+    assert_eq!(
+        result,
+        Err(Mismatch {
+            left: vec![(1, 5.0)],
+            right: vec![(2, 7.0)],
+        })
+    );
+}
+
+#[test]
+fn test_length_mismatch() {
+    let result = unordered::abs_diff_eq(vec![1.0, 2.0], vec![2.0, 1.0, 3.0], 0.1);
+    assert_eq!(
+        result,
+        Err(Mismatch {
+            left: vec![],
+            right: vec![(2, 3.0)],
+        })
+    );
+}
+
+#[test]
+fn test_comparator() {
+    let cmp = approx::either(AbsDiff::default().epsilon(1e-12), approx::Ulps::default());
+    assert_unordered_approx_eq!([0.1 + 0.2, 1e-13], [0.0, 0.3], using = cmp);
+}
+
+#[test]
+#[should_panic(expected = "unmatched left  = [(1, 5.0)]")]
+fn test_panic() {
+    assert_unordered_relative_eq!([1.0, 5.0], [1.0, 6.0]);
+}
+
+#[cfg(feature = "num-complex")]
+mod test_complex {
+    use num_complex::Complex;
+
+    #[test]
+    fn test_eigenvalues() {
+        let xs = [
+            Complex::new(1.0, -2.0),
+            Complex::new(1.0, 2.0),
+            Complex::new(-3.0, 0.0),
+        ];
+        let ys = [
+            Complex::new(-3.0, 1e-15),
+            Complex::new(1.0, 2.0 + 1e-12),
+            Complex::new(1.0, -2.0),
+        ];
+        assert_unordered_relative_eq!(xs, ys, epsilon = 1e-12, max_relative = 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "unmatched right = [(0, Complex { re: 1.0, im: 2.0 })]")]
+    fn test_conjugate() {
+        let xs = [Complex::new(1.0, -2.0), Complex::new(1.0, -2.0)];
+        let ys = [Complex::new(1.0, 2.0), Complex::new(1.0, -2.0)];
+        assert_unordered_abs_diff_eq!(xs, ys, epsilon = 1e-9);
+    }
+}
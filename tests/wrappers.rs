@@ -0,0 +1,176 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test the core wrapper and marker types
+
+#![no_std]
+
+#[macro_use]
+extern crate approx;
+
+use core::cmp::Reverse;
+use core::marker::PhantomData;
+use core::num::{NonZeroI32, NonZeroU8, NonZeroUsize, Saturating, Wrapping};
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+mod wrapping {
+    use super::*;
+
+    #[test]
+    fn test_abs_diff() {
+        assert_abs_diff_eq!(Wrapping(1.0f32), Wrapping(1.0f32));
+        assert_abs_diff_eq!(Wrapping(1.0f64), Wrapping(1.5f64), epsilon = 0.5);
+        assert_abs_diff_ne!(Wrapping(1.0f64), Wrapping(1.5f64), epsilon = 0.4);
+        assert_abs_diff_eq!(Wrapping(3u8), Wrapping(5u8), epsilon = 2);
+    }
+
+    #[test]
+    fn test_abs_diff_extremes() {
+        assert_abs_diff_ne!(Wrapping(i32::MIN), Wrapping(1i32));
+        assert_abs_diff_ne!(Wrapping(i32::MAX), Wrapping(i32::MIN), epsilon = i32::MAX);
+        assert_abs_diff_eq!(Wrapping(i32::MIN), Wrapping(i32::MIN + 2), epsilon = 2);
+        assert_abs_diff_eq!(Wrapping(u64::MAX), Wrapping(u64::MAX - 1), epsilon = 1);
+    }
+
+    #[test]
+    fn test_relative() {
+        assert_relative_eq!(Wrapping(1.0f64), Wrapping(1.0 + 1e-16));
+        assert_relative_ne!(Wrapping(1.0f64), Wrapping(1.001), max_relative = 1e-4);
+    }
+
+    #[test]
+    fn test_ulps() {
+        assert_ulps_eq!(Wrapping(2.0f32), Wrapping(2.0 + 2.0 * f32::EPSILON));
+        assert_ulps_ne!(
+            Wrapping(2.0f32),
+            Wrapping(2.0 + 2.0 * f32::EPSILON),
+            max_ulps = 0
+        );
+    }
+}
+
+mod saturating {
+    use super::*;
+
+    #[test]
+    fn test_abs_diff() {
+        assert_abs_diff_eq!(Saturating(7i32), Saturating(9i32), epsilon = 2);
+        assert_abs_diff_ne!(Saturating(7i32), Saturating(10i32), epsilon = 2);
+    }
+
+    #[test]
+    fn test_abs_diff_extremes() {
+        assert_abs_diff_ne!(
+            Saturating(i64::MIN),
+            Saturating(i64::MAX),
+            epsilon = i64::MAX
+        );
+        assert_abs_diff_eq!(Saturating(i8::MAX), Saturating(i8::MAX - 1), epsilon = 1);
+        assert_abs_diff_ne!(Saturating(i8::MIN), Saturating(i8::MAX), epsilon = -1);
+    }
+
+    #[test]
+    fn test_relative_and_ulps() {
+        assert_relative_eq!(Saturating(100.0f64), Saturating(101.0), max_relative = 0.01);
+        assert_ulps_eq!(Saturating(1.0f64), Saturating(1.0 + f64::EPSILON));
+    }
+}
+
+mod reverse {
+    use super::*;
+
+    #[test]
+    fn test_delegates() {
+        assert_abs_diff_eq!(Reverse(1.0f64), Reverse(1.25), epsilon = 0.25);
+        assert_relative_eq!(Reverse(1.0f64), Reverse(1.0 + 1e-16));
+        assert_ulps_ne!(Reverse(1.0f32), Reverse(2.0f32));
+    }
+}
+
+mod non_zero {
+    use super::*;
+
+    #[test]
+    fn test_abs_diff() {
+        let one = NonZeroU8::new(1).unwrap();
+        let three = NonZeroU8::new(3).unwrap();
+        assert_abs_diff_eq!(one, one);
+        assert_abs_diff_ne!(one, three);
+        assert_abs_diff_eq!(three, one, epsilon = 2);
+
+        let minus_five = NonZeroI32::new(-5).unwrap();
+        let five = NonZeroI32::new(5).unwrap();
+        assert_abs_diff_eq!(minus_five, five, epsilon = 10);
+        assert_abs_diff_ne!(five, minus_five, epsilon = 9);
+
+        assert_eq!(NonZeroUsize::default_epsilon(), 0);
+    }
+}
+
+mod unit {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Metres;
+
+    #[derive(Debug, PartialEq)]
+    struct Tagged<T, U> {
+        value: T,
+        tag: PhantomData<U>,
+    }
+
+    impl<T: AbsDiffEq, U: PartialEq> AbsDiffEq for Tagged<T, U> {
+        type Epsilon = T::Epsilon;
+
+        fn default_epsilon() -> T::Epsilon {
+            T::default_epsilon()
+        }
+
+        fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+            T::abs_diff_eq(&self.value, &other.value, epsilon)
+                && self.tag.abs_diff_eq(&other.tag, ())
+        }
+    }
+
+    #[test]
+    fn test_unit() {
+        assert_abs_diff_eq!((), ());
+        assert_relative_eq!((), ());
+        assert_ulps_eq!((), ());
+        assert!(().relative_eq(&(), (), ()));
+        assert!(().ulps_eq(&(), (), 0));
+    }
+
+    #[test]
+    fn test_phantom_data() {
+        assert_abs_diff_eq!(PhantomData::<f32>, PhantomData::<f32>);
+        assert_relative_eq!(PhantomData::<str>, PhantomData::<str>);
+        assert_ulps_eq!(PhantomData::<[u8]>, PhantomData::<[u8]>);
+    }
+
+    #[test]
+    fn test_generic_struct() {
+        let a = Tagged::<f64, Metres> {
+            value: 1.0,
+            tag: PhantomData,
+        };
+        let b = Tagged::<f64, Metres> {
+            value: 1.5,
+            tag: PhantomData,
+        };
+        assert_abs_diff_eq!(a, b, epsilon = 0.5);
+        assert_abs_diff_ne!(a, b);
+    }
+}